@@ -0,0 +1,59 @@
+//! Benchmarks of parsing and both parts of every registered day, against the
+//! real inputs and against large synthetic inputs.
+//!
+//! Compare against a saved baseline with:
+//!
+//! ```sh
+//! cargo bench --bench solutions -- --save-baseline before
+//! cargo bench --bench solutions -- --baseline before
+//! ```
+
+mod synthetic;
+
+use std::hint::black_box;
+
+use aoc22::{
+    config::Config,
+    solutions::{self, Day, Part},
+    utils::io::{self, Source},
+};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+
+/// Approximate number of lines in each synthetic input.
+const SYNTHETIC_SIZE: usize = 100_000;
+
+fn bench_input(c: &mut Criterion, day: &Day, name: &str, raw: &str) {
+    let mut group = c.benchmark_group(format!("day{}", day.day));
+
+    group.bench_with_input(BenchmarkId::new("parse", name), raw, |b, raw| {
+        b.iter(|| day.parse(black_box(raw)).unwrap())
+    });
+
+    let input = day.parse(raw).unwrap();
+    for part in Part::BOTH {
+        group.bench_with_input(
+            BenchmarkId::new(format!("part{}", part), name),
+            &input,
+            |b, input| b.iter(|| day.solve(part, black_box(input)).unwrap()),
+        );
+    }
+
+    group.finish();
+}
+
+fn bench_solutions(c: &mut Criterion) {
+    let config = Config::load().unwrap_or_default();
+
+    for day in solutions::all() {
+        match io::read(day.day, &Source::Search, &config) {
+            Ok(input) => bench_input(c, day, "real", &input.text),
+            Err(e) => eprintln!("Skipping real input for day {}: {}", day.day, e),
+        }
+        if let Some(raw) = synthetic::input(day.day, SYNTHETIC_SIZE) {
+            bench_input(c, day, "synthetic", &raw);
+        }
+    }
+}
+
+criterion_group!(benches, bench_solutions);
+criterion_main!(benches);
@@ -0,0 +1,145 @@
+//! Generators of large, valid puzzle inputs for benchmarking.
+
+use std::fmt::Write;
+
+/// A small deterministic xorshift generator, so every run benchmarks the
+/// same inputs.
+struct Rng(u64);
+
+impl Rng {
+    fn new() -> Self {
+        Self(0x2545_f491_4f6c_dd1d)
+    }
+
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// A number in `lo..=hi`.
+    fn range(&mut self, lo: u64, hi: u64) -> u64 {
+        lo + self.next() % (hi - lo + 1)
+    }
+
+    fn letter(&mut self) -> char {
+        const LETTERS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+        LETTERS[self.range(0, LETTERS.len() as u64 - 1) as usize] as char
+    }
+}
+
+/// A synthetic input of roughly `size` lines (or characters for day 6), or
+/// `None` if there is no generator for `day`.
+pub fn input(day: u32, size: usize) -> Option<String> {
+    let mut rng = Rng::new();
+    let mut s = String::new();
+
+    match day {
+        1 => {
+            for _ in 0..size {
+                for _ in 0..rng.range(1, 5) {
+                    writeln!(s, "{}", rng.range(1000, 60000)).unwrap();
+                }
+                s.push('\n');
+            }
+        }
+        2 => {
+            for _ in 0..size {
+                let opponent = (b'A' + rng.range(0, 2) as u8) as char;
+                let code = (b'X' + rng.range(0, 2) as u8) as char;
+                writeln!(s, "{} {}", opponent, code).unwrap();
+            }
+        }
+        3 => {
+            // Every rucksack shares an item between its compartments and
+            // every group of three shares a badge.
+            for _ in 0..size / 3 {
+                let badge = rng.letter();
+                for _ in 0..3 {
+                    let common = rng.letter();
+                    let half = rng.range(8, 24) as usize;
+                    s.push(badge);
+                    s.push(common);
+                    s.extend((2..half).map(|_| rng.letter()));
+                    s.push(common);
+                    s.extend((1..half).map(|_| rng.letter()));
+                    s.push('\n');
+                }
+            }
+        }
+        4 => {
+            for _ in 0..size {
+                let (a, c) = (rng.range(1, 90), rng.range(1, 90));
+                let (b, d) = (a + rng.range(0, 9), c + rng.range(0, 9));
+                writeln!(s, "{}-{},{}-{}", a, b, c, d).unwrap();
+            }
+        }
+        5 => {
+            const STACKS: usize = 9;
+            let mut heights = [0; STACKS];
+            let mut rows = Vec::new();
+            for row in 0..size / 20 {
+                let crates: Vec<String> = (0..STACKS)
+                    .map(|stack| {
+                        if row < 8 || rng.range(0, 1) == 0 {
+                            heights[stack] += 1;
+                            format!("[{}]", rng.letter().to_ascii_uppercase())
+                        } else {
+                            "   ".to_string()
+                        }
+                    })
+                    .collect();
+                rows.push(crates);
+            }
+            // Rows are generated bottom up; a crate may only sit on another.
+            for stack in 0..STACKS {
+                let mut column: Vec<String> = rows.iter().map(|r| r[stack].clone()).collect();
+                column.sort_by_key(|c| c.trim().is_empty());
+                for (row, cell) in rows.iter_mut().zip(column) {
+                    row[stack] = cell;
+                }
+            }
+            for row in rows.iter().rev() {
+                writeln!(s, "{}", row.join(" ")).unwrap();
+            }
+            let labels: Vec<String> = (1..=STACKS).map(|i| format!(" {} ", i)).collect();
+            writeln!(s, "{}\n", labels.join(" ")).unwrap();
+
+            for _ in 0..size {
+                let origin = loop {
+                    let origin = rng.range(0, STACKS as u64 - 1) as usize;
+                    if heights[origin] > 0 {
+                        break origin;
+                    }
+                };
+                let destination = loop {
+                    let destination = rng.range(0, STACKS as u64 - 1) as usize;
+                    if destination != origin {
+                        break destination;
+                    }
+                };
+                let quantity = rng.range(1, heights[origin].min(10) as u64) as usize;
+                heights[origin] -= quantity;
+                heights[destination] += quantity;
+                writeln!(
+                    s,
+                    "move {} from {} to {}",
+                    quantity,
+                    origin + 1,
+                    destination + 1
+                )
+                .unwrap();
+            }
+        }
+        6 => {
+            // Windows over a repeating three letter pattern are never unique,
+            // so both markers are only found in the final stretch.
+            s.extend("abc".chars().cycle().take(size));
+            s.push_str("abcdefghijklmnopqrstuvwxyz");
+        }
+        _ => return None,
+    }
+
+    Some(s)
+}
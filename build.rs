@@ -0,0 +1,42 @@
+//! With the `embed-inputs` feature, generate `$OUT_DIR/inputs.rs` listing
+//! every `src/inputs/day{N}.txt` so that `utils::io` can include them in the
+//! binary.
+
+use std::{env, fs, path::Path};
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    if env::var_os("CARGO_FEATURE_EMBED_INPUTS").is_none() {
+        return;
+    }
+
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").expect("Set by cargo");
+    let inputs = Path::new(&manifest_dir).join("src/inputs");
+    println!("cargo:rerun-if-changed={}", inputs.display());
+
+    let mut days: Vec<(u32, String)> = fs::read_dir(&inputs)
+        .expect("src/inputs must exist to embed inputs")
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            let day = path
+                .file_name()?
+                .to_str()?
+                .strip_prefix("day")?
+                .strip_suffix(".txt")?
+                .parse()
+                .ok()?;
+            println!("cargo:rerun-if-changed={}", path.display());
+            Some((day, path.display().to_string()))
+        })
+        .collect();
+    days.sort();
+
+    let mut source = String::from("pub static INPUTS: &[(u32, &str)] = &[\n");
+    for (day, path) in days {
+        source.push_str(&format!("    ({}, include_str!({:?})),\n", day, path));
+    }
+    source.push_str("];\n");
+
+    let out = Path::new(&env::var("OUT_DIR").expect("Set by cargo")).join("inputs.rs");
+    fs::write(out, source).expect("Unable to write embedded inputs");
+}
@@ -0,0 +1,651 @@
+//! Command-line interface for running solutions.
+
+mod all;
+mod bench;
+mod check;
+mod crane;
+mod fetch;
+mod new;
+mod output;
+mod replay;
+mod run;
+mod submit;
+mod table;
+mod verify;
+mod watch;
+
+use std::{process::ExitCode, time::Duration};
+
+use crate::{
+    cli::{crane::Crane, output::Format},
+    config::Config,
+    solutions::{self, Part},
+    utils::io::Source,
+};
+
+const USAGE: &str = "\
+Usage: aoc22 <COMMAND> [OPTIONS]
+
+Commands:
+  run <DAYS>     Solve the selected days
+  verify [DAYS]  Check the answers for the real inputs against answers.toml
+  bench [DAYS]   Time parsing and both parts of the selected days
+  all [DAYS]     Solve the selected days in parallel and print a summary
+  check [DAYS]   Validate the inputs of the selected days, reporting every
+                 problem found
+  crane <MODEL>  Rearrange the day 5 stacks with a crane model: 9000, 9001 or
+                 limited:<N> for one lifting up to N crates per trip
+  replay [MODEL] Animate the day 5 crane one move at a time, highlighting the
+                 crates it moved. MODEL defaults to 9000
+  watch <DAY>    Re-run a day against its examples and input when they change
+  new <DAY>      Create and register the module, input and examples of a day
+  fetch <DAY>    Download the input of a day, unless it is already saved
+  submit <DAY> <PART>
+                 Solve a part and submit the answer, unless a past guess
+                 rules it out
+  help           Print this message
+
+Options:
+  -f, --format <FORMAT>  Output `text` (default) or `json`, for run, verify,
+                         bench and all
+
+Arguments:
+  <DAYS>  A single day (3), an inclusive range (1..=5), a half-open range
+          (1..4) or `all` for every implemented day. Defaults to `all` where
+          optional
+
+Run options:
+  -p, --part <PART>   Only run part 1 or part 2
+  -i, --input <PATH>  Read the input of a single day from PATH, or from stdin
+                      if PATH is `-`. Also accepted by check and crane
+
+Bench options:
+  -n, --iterations <N>  Number of times to run each stage (default: 10)
+
+Watch options:
+      --interval <MS>  Milliseconds between checks for changes (default: 500)
+
+Replay options:
+      --interval <MS>  Milliseconds between moves (default: 200)
+      --step           Start paused, to step through moves by hand
+  -i, --input <PATH>   Replay the input at PATH. Not stdin, which is read for
+                       the controls
+
+While replaying, enter `n` (or nothing) to step forward, `b` to step back,
+`g N` to go to move N, `p` to play or pause, `+` or `-` to change speed and
+`q` to quit.
+
+Inputs are otherwise read from `day{N}.txt` in $AOC_INPUT_DIR, the
+`input_dir` set in aoc22.toml (or the file named by $AOC_CONFIG), or
+src/inputs, whichever is found first. Builds with the `embed-inputs` feature
+use the inputs compiled into the binary instead, where there is one.
+
+`fetch` and `submit` authenticate with the session cookie in $AOC_SESSION or
+the config file's `session`. `fetch` saves inputs to $AOC_INPUT_DIR or
+`input_dir` if set, or else src/inputs. `submit` records its guesses in
+guesses.toml.";
+
+impl TryFrom<&str> for Part {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("Invalid part {:?}, expected 1 or 2", value)),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Command {
+    /// Run the selected days, optionally restricted to a single part.
+    Run {
+        days: Vec<u32>,
+        part: Option<Part>,
+        input: Source,
+        format: Format,
+    },
+    /// Check the selected days against their known answers.
+    Verify {
+        days: Vec<u32>,
+        format: Format,
+    },
+    /// Solve the selected days concurrently and summarise them in a table.
+    All {
+        days: Vec<u32>,
+        format: Format,
+    },
+    /// Time the selected days over a number of iterations.
+    Bench {
+        days: Vec<u32>,
+        iterations: usize,
+        format: Format,
+    },
+    /// Report every problem in the inputs of the selected days.
+    Check {
+        days: Vec<u32>,
+        input: Source,
+    },
+    /// Solve day 5 with a particular model of crane.
+    Crane {
+        crane: Crane,
+        input: Source,
+    },
+    /// Animate day 5 with a particular model of crane.
+    Replay {
+        crane: Crane,
+        interval: Duration,
+        step: bool,
+        input: Source,
+    },
+    /// Re-run a single day whenever its input or examples change.
+    Watch {
+        day: u32,
+        interval: Duration,
+    },
+    /// Scaffold a day that is not implemented yet.
+    New {
+        day: u32,
+    },
+    /// Download the input of a day unless it is already saved.
+    Fetch {
+        day: u32,
+    },
+    /// Submit the answer to one part of a day.
+    Submit {
+        day: u32,
+        part: Part,
+    },
+    Help,
+}
+
+/// Parse a day selection such as `3`, `1..=5`, `1..4` or `all` into the list
+/// of days it covers. Every selected day must be implemented.
+fn parse_days(s: &str) -> Result<Vec<u32>, String> {
+    if s == "all" {
+        return Ok(all_days());
+    }
+
+    let parse_day = |d: &str| {
+        d.parse::<u32>()
+            .map_err(|_| format!("Invalid day {:?} in selection {:?}", d, s))
+    };
+
+    let days: Vec<u32> = if let Some((start, end)) = s.split_once("..=") {
+        (parse_day(start)?..=parse_day(end)?).collect()
+    } else if let Some((start, end)) = s.split_once("..") {
+        (parse_day(start)?..parse_day(end)?).collect()
+    } else {
+        vec![parse_day(s)?]
+    };
+
+    if days.is_empty() {
+        return Err(format!("Day selection {:?} is empty", s));
+    }
+    if let Some(day) = days.iter().find(|&&d| solutions::get(d).is_none()) {
+        let available = all_days()
+            .iter()
+            .map(u32::to_string)
+            .collect::<Vec<_>>()
+            .join(", ");
+        return Err(format!(
+            "Day {} is not implemented (available: {})",
+            day, available
+        ));
+    }
+
+    Ok(days)
+}
+
+fn all_days() -> Vec<u32> {
+    solutions::all().iter().map(|d| d.day).collect()
+}
+
+/// Options shared between commands. Each command lists the flags it accepts.
+#[derive(Debug, Default)]
+struct Options {
+    /// The argument that isn't a flag: a day selection, or for `crane` and
+    /// `replay` a crane model.
+    positional: Option<String>,
+    part: Option<Part>,
+    input: Option<Source>,
+    iterations: Option<usize>,
+    interval: Option<Duration>,
+    format: Format,
+    step: bool,
+    help: bool,
+}
+
+fn unexpected(arg: &str) -> String {
+    if arg.starts_with('-') {
+        format!("Unknown option {:?}", arg)
+    } else {
+        format!("Unexpected argument {:?}", arg)
+    }
+}
+
+/// Parse the arguments following a command, accepting one positional
+/// argument and the long forms of the flags in `accepted` (and their short
+/// aliases).
+fn parse_options(
+    mut args: impl Iterator<Item = String>,
+    accepted: &[&str],
+) -> Result<Options, String> {
+    let mut options = Options::default();
+
+    while let Some(arg) = args.next() {
+        let flag = match arg.as_str() {
+            "-p" => "--part",
+            "-i" => "--input",
+            "-n" => "--iterations",
+            "-f" => "--format",
+            "-h" => "--help",
+            other => other,
+        };
+        if flag.starts_with('-') && flag != "--help" && !accepted.contains(&flag) {
+            return Err(unexpected(&arg));
+        }
+
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("Missing value for {}", arg))
+        };
+        match flag {
+            "--help" => options.help = true,
+            "--part" => options.part = Some(Part::try_from(value()?.as_str())?),
+            "--input" => options.input = Some(Source::from(value()?.as_str())),
+            "--iterations" => {
+                let n = value()?;
+                options.iterations = match n.parse() {
+                    Ok(n) if n > 0 => Some(n),
+                    _ => return Err(format!("Invalid iteration count {:?}", n)),
+                };
+            }
+            "--interval" => {
+                let ms = value()?;
+                options.interval = match ms.parse() {
+                    Ok(ms) if ms > 0 => Some(Duration::from_millis(ms)),
+                    _ => return Err(format!("Invalid interval {:?}", ms)),
+                };
+            }
+            "--format" => options.format = Format::try_from(value()?.as_str())?,
+            "--step" => options.step = true,
+            positional if options.positional.is_none() && !positional.starts_with('-') => {
+                options.positional = Some(positional.to_string())
+            }
+            other => return Err(unexpected(other)),
+        }
+    }
+
+    Ok(options)
+}
+
+/// Parse the arguments of `new` and `fetch`, which take a single puzzle day
+/// whether or not it is implemented yet.
+fn parse_puzzle_day(command: &str, args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let mut day = None;
+    for arg in args {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(Command::Help),
+            d if day.is_none() && !d.starts_with('-') => {
+                day = match d.parse() {
+                    Ok(d @ 1..=25) => Some(d),
+                    _ => return Err(format!("Invalid day {:?}, expected 1 to 25", d)),
+                }
+            }
+            other => return Err(unexpected(other)),
+        }
+    }
+
+    let day = day.ok_or("Missing day")?;
+    match command {
+        "new" if solutions::get(day).is_some() => {
+            Err(format!("Day {} is already implemented", day))
+        }
+        "new" => Ok(Command::New { day }),
+        _ => Ok(Command::Fetch { day }),
+    }
+}
+
+/// Parse the arguments of `submit`: a single day and a part.
+fn parse_submit(args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let mut positional = Vec::new();
+    for arg in args {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(Command::Help),
+            a if a.starts_with('-') => return Err(unexpected(a)),
+            _ => positional.push(arg),
+        }
+    }
+
+    match &positional[..] {
+        [day, part] => match parse_days(day)?[..] {
+            [day] => Ok(Command::Submit {
+                day,
+                part: Part::try_from(part.as_str())?,
+            }),
+            _ => Err("submit takes a single day".to_string()),
+        },
+        [_] => Err("Missing part".to_string()),
+        [] => Err("Missing day and part".to_string()),
+        [_, _, extra, ..] => Err(unexpected(extra)),
+    }
+}
+
+/// The input to read, checking that `--input` is only given for a single day.
+fn single_day_input(days: &[u32], input: Option<Source>) -> Result<Source, String> {
+    let input = input.unwrap_or_default();
+    if input != Source::Search && days.len() != 1 {
+        return Err("--input can only be used with a single day".to_string());
+    }
+    Ok(input)
+}
+
+/// Parse the command-line arguments, excluding the program name.
+pub fn parse_args<I>(args: I) -> Result<Command, String>
+where
+    I: IntoIterator<Item = String>,
+{
+    let mut args = args.into_iter();
+
+    let command = match args.next() {
+        Some(command) => command,
+        None => return Err("Missing command".to_string()),
+    };
+    let accepted: &[&str] = match command.as_str() {
+        "run" => &["--part", "--input", "--format"],
+        "verify" | "all" => &["--format"],
+        "bench" => &["--iterations", "--format"],
+        "check" => &["--input"],
+        "watch" => &["--interval"],
+        "crane" => &["--input"],
+        "replay" => &["--input", "--interval", "--step"],
+        "new" | "fetch" => return parse_puzzle_day(&command, args),
+        "submit" => return parse_submit(args),
+        "-h" | "--help" | "help" => return Ok(Command::Help),
+        other => return Err(format!("Unknown command {:?}", other)),
+    };
+
+    let options = parse_options(args, accepted)?;
+    if options.help {
+        return Ok(Command::Help);
+    }
+    let format = options.format;
+    let crane = || {
+        options
+            .positional
+            .as_deref()
+            .map(Crane::try_from)
+            .transpose()
+    };
+    let days = match command.as_str() {
+        "crane" | "replay" => None,
+        _ => options.positional.as_deref().map(parse_days).transpose()?,
+    };
+
+    Ok(match command.as_str() {
+        "run" => {
+            let days = days.ok_or("Missing day selection")?;
+            let input = single_day_input(&days, options.input)?;
+            Command::Run {
+                days,
+                part: options.part,
+                input,
+                format,
+            }
+        }
+        "verify" => Command::Verify {
+            days: days.unwrap_or_else(all_days),
+            format,
+        },
+        "all" => Command::All {
+            days: days.unwrap_or_else(all_days),
+            format,
+        },
+        "bench" => Command::Bench {
+            days: days.unwrap_or_else(all_days),
+            iterations: options.iterations.unwrap_or(10),
+            format,
+        },
+        "check" => {
+            let days = days.unwrap_or_else(all_days);
+            let input = single_day_input(&days, options.input)?;
+            Command::Check { days, input }
+        }
+        "crane" => Command::Crane {
+            crane: crane()?.ok_or("Missing crane model")?,
+            input: options.input.unwrap_or_default(),
+        },
+        "replay" => Command::Replay {
+            crane: crane()?.unwrap_or(Crane::CrateMover9000),
+            interval: options.interval.unwrap_or(Duration::from_millis(200)),
+            step: options.step,
+            input: match options.input.unwrap_or_default() {
+                Source::Stdin => {
+                    return Err("replay reads its controls from stdin, not the input".to_string())
+                }
+                input => input,
+            },
+        },
+        "watch" => match days.ok_or("Missing day")?[..] {
+            [day] => Command::Watch {
+                day,
+                interval: options.interval.unwrap_or(Duration::from_millis(500)),
+            },
+            _ => return Err("watch takes a single day".to_string()),
+        },
+        _ => unreachable!("Accepted options are only listed for known commands"),
+    })
+}
+
+/// Entry point for the binary. Exits with 1 if any day fails and 2 on usage
+/// errors.
+pub fn main<I>(args: I) -> ExitCode
+where
+    I: IntoIterator<Item = String>,
+{
+    let command = match parse_args(args) {
+        Ok(command) => command,
+        Err(e) => {
+            eprintln!("error: {}\n\n{}", e, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+    let config = match Config::load() {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let success = match command {
+        Command::Run {
+            days,
+            part,
+            input,
+            format,
+        } => run::run(&days, part, &input, format, &config),
+        Command::Verify { days, format } => verify::verify(&days, format, &config),
+        Command::All { days, format } => all::all(&days, format, &config),
+        Command::Bench {
+            days,
+            iterations,
+            format,
+        } => bench::bench(&days, iterations, format, &config),
+        Command::Check { days, input } => check::check(&days, &input, &config),
+        Command::Crane { crane, input } => crane::crane(crane, &input, &config),
+        Command::Replay {
+            crane,
+            interval,
+            step,
+            input,
+        } => replay::replay(crane, interval, step, &input, &config),
+        Command::Watch { day, interval } => watch::watch(day, interval, &config),
+        Command::New { day } => new::new(day),
+        Command::Fetch { day } => fetch::fetch(day, &config),
+        Command::Submit { day, part } => submit::submit(day, part, &config),
+        Command::Help => {
+            println!("{}", USAGE);
+            true
+        }
+    };
+
+    if success {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(s: &str) -> Vec<String> {
+        s.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn test_parse_days() {
+        assert_eq!(parse_days("3"), Ok(vec![3]));
+        assert_eq!(parse_days("1..=5"), Ok(vec![1, 2, 3, 4, 5]));
+        assert_eq!(parse_days("2..4"), Ok(vec![2, 3]));
+        assert_eq!(parse_days("all"), Ok(all_days()));
+
+        assert!(parse_days("0").is_err());
+        assert!(parse_days("26").is_err());
+        assert!(parse_days("4..2").is_err());
+        assert!(parse_days("x").is_err());
+    }
+
+    #[test]
+    fn test_parse_args() {
+        assert_eq!(
+            parse_args(args("run 3 --part 2")),
+            Ok(Command::Run {
+                days: vec![3],
+                part: Some(Part::Two),
+                input: Source::Search,
+                format: Format::Text,
+            })
+        );
+        assert_eq!(
+            parse_args(args("run -p 1 1..=2")),
+            Ok(Command::Run {
+                days: vec![1, 2],
+                part: Some(Part::One),
+                input: Source::Search,
+                format: Format::Text,
+            })
+        );
+        assert_eq!(
+            parse_args(args("run 5 --input -")),
+            Ok(Command::Run {
+                days: vec![5],
+                part: None,
+                input: Source::Stdin,
+                format: Format::Text,
+            })
+        );
+        assert_eq!(
+            parse_args(args("verify --format json")),
+            Ok(Command::Verify {
+                days: all_days(),
+                format: Format::Json
+            })
+        );
+        assert_eq!(
+            parse_args(args("bench 5 -n 100")),
+            Ok(Command::Bench {
+                days: vec![5],
+                iterations: 100,
+                format: Format::Text,
+            })
+        );
+        assert_eq!(
+            parse_args(args("all 2..=3")),
+            Ok(Command::All {
+                days: vec![2, 3],
+                format: Format::Text
+            })
+        );
+        assert_eq!(
+            parse_args(args("check 4 -i -")),
+            Ok(Command::Check {
+                days: vec![4],
+                input: Source::Stdin,
+            })
+        );
+        assert_eq!(
+            parse_args(args("crane limited:3 -i day5.txt")),
+            Ok(Command::Crane {
+                crane: Crane::Limited(3),
+                input: Source::File("day5.txt".into()),
+            })
+        );
+        assert_eq!(
+            parse_args(args("replay --step --interval 50")),
+            Ok(Command::Replay {
+                crane: Crane::CrateMover9000,
+                interval: Duration::from_millis(50),
+                step: true,
+                input: Source::Search,
+            })
+        );
+        assert_eq!(
+            parse_args(args("replay -i day5.txt limited:2")),
+            Ok(Command::Replay {
+                crane: Crane::Limited(2),
+                interval: Duration::from_millis(200),
+                step: false,
+                input: Source::File("day5.txt".into()),
+            })
+        );
+        assert_eq!(
+            parse_args(args("watch 5 --interval 100")),
+            Ok(Command::Watch {
+                day: 5,
+                interval: Duration::from_millis(100),
+            })
+        );
+        assert_eq!(parse_args(args("new 25")), Ok(Command::New { day: 25 }));
+        assert_eq!(parse_args(args("fetch 6")), Ok(Command::Fetch { day: 6 }));
+        assert_eq!(
+            parse_args(args("submit 5 2")),
+            Ok(Command::Submit {
+                day: 5,
+                part: Part::Two
+            })
+        );
+        assert_eq!(parse_args(args("--help")), Ok(Command::Help));
+
+        assert!(parse_args(args("run")).is_err());
+        assert!(parse_args(args("run 3 --part 3")).is_err());
+        assert!(parse_args(args("run 3 4")).is_err());
+        assert!(parse_args(args("run all --input day.txt")).is_err());
+        assert!(parse_args(args("verify --part 1")).is_err());
+        assert!(parse_args(args("bench -n 0")).is_err());
+        assert!(parse_args(args("all --format xml")).is_err());
+        assert!(parse_args(args("check --input day.txt")).is_err());
+        assert!(parse_args(args("check 4 --format json")).is_err());
+        assert!(parse_args(args("crane")).is_err());
+        assert!(parse_args(args("crane limited:0")).is_err());
+        assert!(parse_args(args("crane 9002")).is_err());
+        assert!(parse_args(args("crane 9001 --step")).is_err());
+        assert!(parse_args(args("replay 9001 --interval 0")).is_err());
+        assert!(parse_args(args("replay 5")).is_err());
+        assert!(parse_args(args("replay --input -")).is_err());
+        assert!(parse_args(args("watch 5 --step")).is_err());
+        assert!(parse_args(args("watch 1..=2")).is_err());
+        assert!(parse_args(args("watch 5 --format json")).is_err());
+        assert!(parse_args(args("new 5")).is_err());
+        assert!(parse_args(args("new 26")).is_err());
+        assert!(parse_args(args("fetch 1..=2")).is_err());
+        assert!(parse_args(args("submit 5")).is_err());
+        assert!(parse_args(args("submit 1..=2 1")).is_err());
+        assert!(parse_args(args("submit 5 1 2")).is_err());
+        assert!(parse_args(args("solve 3")).is_err());
+    }
+}
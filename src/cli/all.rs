@@ -0,0 +1,210 @@
+//! `all`: solve every selected day concurrently and summarise the results in
+//! a single table.
+
+use std::{
+    any::Any,
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use serde::Serialize;
+
+use crate::{
+    cli::{
+        output::{self, Format},
+        table::Table,
+    },
+    config::Config,
+    error::Result,
+    solutions::{self, Answer, Day, Part},
+    utils::{
+        io::{self, Source},
+        timing,
+    },
+};
+
+/// The outcome of solving one day. Errors and panics are kept as messages so
+/// one failing day doesn't hide the others.
+struct Report {
+    parts: [(Result<Answer, String>, Option<Duration>); 2],
+    time: Duration,
+}
+
+fn solve(day: &'static Day, config: &Config) -> Report {
+    let start = Instant::now();
+    let input: Result<_> =
+        io::read(day.day, &Source::Search, config).and_then(|raw| day.parse_input(&raw));
+    let parts = Part::BOTH.map(|part| match &input {
+        Ok(input) => {
+            let (answer, elapsed) = timing::time(|| day.solve(part, input));
+            (answer.map_err(|e| e.to_string()), Some(elapsed))
+        }
+        Err(e) => (Err(e.to_string()), None),
+    });
+
+    Report {
+        parts,
+        time: start.elapsed(),
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+/// Apply `f` to every item on a pool of worker threads, one per available
+/// core. Results are returned in the order of `items`; a panic in `f` is
+/// caught and returned as the panic message.
+fn parallel_map<T, R, F>(items: &[T], f: F) -> Vec<Result<R, String>>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    let workers = thread::available_parallelism()
+        .map_or(1, |n| n.get())
+        .min(items.len());
+    let next = AtomicUsize::new(0);
+    let results: Mutex<Vec<Option<Result<R, String>>>> =
+        Mutex::new((0..items.len()).map(|_| None).collect());
+
+    thread::scope(|scope| {
+        for _ in 0..workers {
+            scope.spawn(|| loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                let Some(item) = items.get(i) else { break };
+                let result = panic::catch_unwind(AssertUnwindSafe(|| f(item)))
+                    .map_err(|payload| panic_message(payload.as_ref()));
+                results.lock().unwrap()[i] = Some(result);
+            });
+        }
+    });
+
+    results
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .map(|result| result.expect("Every item is processed by a worker"))
+        .collect()
+}
+
+/// The result of one part of a day. A panic is reported as an error of both
+/// parts.
+#[derive(Debug, Serialize)]
+struct Record {
+    day: u32,
+    title: &'static str,
+    part: u8,
+    answer: Option<Answer>,
+    duration_ns: Option<u128>,
+    error: Option<String>,
+}
+
+fn records(day: &'static Day, report: Result<Report, String>) -> Vec<Record> {
+    Part::BOTH
+        .iter()
+        .enumerate()
+        .map(|(i, part)| {
+            let (answer, duration, error) = match &report {
+                Ok(report) => {
+                    let (result, duration) = &report.parts[i];
+                    (
+                        result.as_ref().ok().cloned(),
+                        duration.map(|d| d.as_nanos()),
+                        result.as_ref().err().cloned(),
+                    )
+                }
+                Err(panic) => (None, None, Some(format!("panicked: {}", panic))),
+            };
+            Record {
+                day: day.day,
+                title: day.title,
+                part: part.number(),
+                answer,
+                duration_ns: duration,
+                error,
+            }
+        })
+        .collect()
+}
+
+/// Solve every selected day in parallel and print a summary table, followed
+/// by the details of any failures. Returns whether every day succeeded.
+pub fn all(days: &[u32], format: Format, config: &Config) -> bool {
+    let days: Vec<&'static Day> = days.iter().filter_map(|&d| solutions::get(d)).collect();
+
+    let start = Instant::now();
+    let reports = parallel_map(&days, |&day| solve(day, config));
+    let wall = start.elapsed();
+
+    let mut table = Table::new(&["Day", "Title", "Part 1", "Part 2", "Time"]).align_right(&[0, 4]);
+    let mut results = Vec::new();
+    for (&day, report) in days.iter().zip(reports) {
+        let time = report
+            .as_ref()
+            .map_or(String::new(), |r| timing::format(r.time));
+        let panicked = report.is_err();
+        let records = records(day, report);
+
+        let mut row = vec![day.day.to_string(), day.title.to_string()];
+        row.extend(records.iter().map(|r| match (&r.answer, panicked) {
+            (Some(answer), _) => answer.to_string(),
+            (None, true) => "PANIC".to_string(),
+            (None, false) => "ERROR".to_string(),
+        }));
+        row.push(time);
+        table.row(row);
+
+        results.extend(records);
+    }
+
+    let success = results.iter().all(|r| r.error.is_none());
+    match format {
+        Format::Json => output::print_json("all", success, &results),
+        Format::Text => {
+            print!("{}", table);
+            println!("\n{} days in {}", days.len(), timing::format(wall));
+            for r in results.iter().filter(|r| r.error.is_some()) {
+                eprintln!(
+                    "error: day {} part {}: {}",
+                    r.day,
+                    r.part,
+                    r.error.as_deref().unwrap_or_default()
+                );
+            }
+        }
+    }
+
+    success
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parallel_map_isolates_panics() {
+        let results = parallel_map(&[1, 2, 3, 4], |&n| {
+            if n == 3 {
+                panic!("three");
+            }
+            n * 10
+        });
+
+        assert_eq!(
+            results,
+            vec![Ok(10), Ok(20), Err("three".to_string()), Ok(40)]
+        );
+    }
+}
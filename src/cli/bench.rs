@@ -0,0 +1,149 @@
+//! `bench`: time each day's parse and both parts over repeated runs.
+
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+
+use crate::{
+    cli::{
+        output::{self, Format},
+        table::Table,
+    },
+    config::Config,
+    error::Result,
+    solutions::{self, Answer, Day, Part},
+    utils::{
+        io::{self, Source},
+        timing::{self, Stats},
+    },
+};
+
+/// Timings of one stage of a day: parsing (`part` is `None`) or a part.
+struct Stage {
+    part: Option<Part>,
+    samples: Vec<Duration>,
+    answer: Option<Answer>,
+}
+
+fn bench_day(day: &Day, iterations: usize, config: &Config) -> Result<[Stage; 3]> {
+    let raw = io::read(day.day, &Source::Search, config)?;
+    let mut stages = [None, Some(Part::One), Some(Part::Two)].map(|part| Stage {
+        part,
+        samples: Vec::with_capacity(iterations),
+        answer: None,
+    });
+
+    for _ in 0..iterations {
+        let [parse, parts @ ..] = &mut stages;
+        let (input, elapsed) = timing::time(|| day.parse_input(&raw));
+        let input = input?;
+        parse.samples.push(elapsed);
+
+        for stage in parts {
+            let part = stage.part.expect("Only the parse stage has no part");
+            let (answer, elapsed) = timing::time(|| day.solve(part, &input));
+            stage.answer = Some(answer?);
+            stage.samples.push(elapsed);
+        }
+    }
+
+    Ok(stages)
+}
+
+/// Timing statistics for one stage of a day. `duration_ns` is the mean.
+#[derive(Debug, Serialize)]
+struct Record {
+    day: u32,
+    part: Option<u8>,
+    answer: Option<Answer>,
+    duration_ns: Option<u128>,
+    min_ns: Option<u128>,
+    median_ns: Option<u128>,
+    max_ns: Option<u128>,
+    iterations: usize,
+    error: Option<String>,
+}
+
+/// Benchmark every selected day, printing min/median/mean/max for each stage
+/// and the total of the mean times. Returns whether every day succeeded.
+pub fn bench(days: &[u32], iterations: usize, format: Format, config: &Config) -> bool {
+    let start = Instant::now();
+    let mut records = Vec::new();
+    let mut stats = Vec::new();
+
+    for day in days.iter().filter_map(|&d| solutions::get(d)) {
+        let stages = match bench_day(day, iterations, config) {
+            Ok(stages) => stages,
+            Err(e) => {
+                records.push(Record {
+                    day: day.day,
+                    part: None,
+                    answer: None,
+                    duration_ns: None,
+                    min_ns: None,
+                    median_ns: None,
+                    max_ns: None,
+                    iterations,
+                    error: Some(e.to_string()),
+                });
+                continue;
+            }
+        };
+
+        for stage in stages {
+            let Some(s) = Stats::from_samples(&stage.samples) else {
+                continue;
+            };
+            stats.push((day.day, stage.part, s));
+            records.push(Record {
+                day: day.day,
+                part: stage.part.map(Part::number),
+                answer: stage.answer,
+                duration_ns: Some(s.mean.as_nanos()),
+                min_ns: Some(s.min.as_nanos()),
+                median_ns: Some(s.median.as_nanos()),
+                max_ns: Some(s.max.as_nanos()),
+                iterations,
+                error: None,
+            });
+        }
+    }
+
+    let success = records.iter().all(|r| r.error.is_none());
+    match format {
+        Format::Json => output::print_json("bench", success, &records),
+        Format::Text => {
+            for record in records.iter().filter(|r| r.error.is_some()) {
+                eprintln!(
+                    "error: day {}: {}",
+                    record.day,
+                    record.error.as_deref().unwrap_or_default()
+                );
+            }
+
+            let mut table = Table::new(&["Day", "Stage", "Min", "Median", "Mean", "Max"])
+                .align_right(&[0, 2, 3, 4, 5]);
+            for (day, part, s) in &stats {
+                table.row(vec![
+                    day.to_string(),
+                    part.map_or("parse".to_string(), |p| format!("part {}", p)),
+                    timing::format(s.min),
+                    timing::format(s.median),
+                    timing::format(s.mean),
+                    timing::format(s.max),
+                ]);
+            }
+
+            let total: Duration = stats.iter().map(|(_, _, s)| s.mean).sum();
+            print!("{}", table);
+            println!(
+                "\nTotal (sum of means): {}, {} iterations in {}",
+                timing::format(total),
+                iterations,
+                timing::format(start.elapsed())
+            );
+        }
+    }
+
+    success
+}
@@ -0,0 +1,34 @@
+//! `check`: validate the inputs of the selected days, reporting every problem
+//! found rather than only the first.
+
+use crate::{
+    config::Config,
+    solutions,
+    utils::io::{self, Source},
+};
+
+/// Check each day's input, printing its problems. Returns whether every input
+/// is valid.
+pub fn check(days: &[u32], source: &Source, config: &Config) -> bool {
+    let mut valid = true;
+    for day in days.iter().filter_map(|&d| solutions::get(d)) {
+        println!("Day {} :: {}", day.day, day.title);
+        let problems = match io::read(day.day, source, config) {
+            Ok(raw) => day.check(&raw),
+            Err(e) => vec![e],
+        };
+
+        match problems.len() {
+            0 => println!("ok"),
+            n => {
+                valid = false;
+                for problem in problems {
+                    println!("{}", problem);
+                }
+                println!("{} problem{} found", n, if n == 1 { "" } else { "s" });
+            }
+        }
+    }
+
+    valid
+}
@@ -0,0 +1,87 @@
+//! `crane`: rearrange the day 5 stacks with a chosen model of crane, to
+//! explore variants of the puzzle.
+
+use crate::{
+    config::Config,
+    error::Result,
+    solutions::{
+        day5::{
+            CrateMover, CrateMover9000, CrateMover9001, Day5, LimitedCrateMover, Movement, Stacks,
+        },
+        Solution,
+    },
+    utils::io::{self, Source},
+};
+
+/// The crane models that can be selected on the command line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Crane {
+    CrateMover9000,
+    CrateMover9001,
+    /// Lifts at most this many crates per trip.
+    Limited(usize),
+}
+
+impl TryFrom<&str> for Crane {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let capacity = value.strip_prefix("limited:").map(str::parse);
+        match (value, capacity) {
+            ("9000", _) => Ok(Crane::CrateMover9000),
+            ("9001", _) => Ok(Crane::CrateMover9001),
+            (_, Some(Ok(capacity @ 1..))) => Ok(Crane::Limited(capacity)),
+            _ => Err(format!(
+                "Invalid crane {:?}, expected 9000, 9001 or limited:<N>",
+                value
+            )),
+        }
+    }
+}
+
+impl Crane {
+    pub fn mover(self) -> Box<dyn CrateMover> {
+        match self {
+            Crane::CrateMover9000 => Box::new(CrateMover9000),
+            Crane::CrateMover9001 => Box::new(CrateMover9001),
+            Crane::Limited(capacity) => Box::new(LimitedCrateMover { capacity }),
+        }
+    }
+
+    pub fn name(self) -> String {
+        match self {
+            Crane::CrateMover9000 => "CrateMover 9000".to_string(),
+            Crane::CrateMover9001 => "CrateMover 9001".to_string(),
+            Crane::Limited(capacity) => format!("Crane lifting up to {} crates", capacity),
+        }
+    }
+}
+
+/// Read and parse the day 5 input.
+pub fn load(source: &Source, config: &Config) -> Result<(Stacks, Vec<Movement>)> {
+    let raw = io::read(Day5::DAY, source, config)?;
+    Day5::parse(&raw.text).map_err(|e| e.with_source(&raw.text).in_file(&raw.path))
+}
+
+/// The crates on top of each stack once `crane` has rearranged them.
+fn top_crates(crane: Crane, source: &Source, config: &Config) -> Result<String> {
+    let (stacks, moves) = load(source, config)?;
+    Ok(stacks
+        .rearrange(&moves, crane.mover().as_ref())?
+        .top_crates())
+}
+
+/// Solve day 5 with `crane`. Returns whether it succeeded.
+pub fn crane(crane: Crane, source: &Source, config: &Config) -> bool {
+    println!("Day {} :: {}", Day5::DAY, Day5::TITLE);
+    match top_crates(crane, source, config) {
+        Ok(top) => {
+            println!("{} :: {}", crane.name(), top);
+            true
+        }
+        Err(e) => {
+            eprintln!("error: {}", e);
+            false
+        }
+    }
+}
@@ -0,0 +1,109 @@
+//! `fetch`: download a day's input into the inputs directory.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    config::Config,
+    error::{AocError, Result},
+    utils::{client::Client, io},
+};
+
+#[derive(Debug, PartialEq, Eq)]
+enum Fetched {
+    /// The input was already saved at this path.
+    Cached(PathBuf),
+    /// The input was downloaded and saved at this path.
+    Downloaded(PathBuf),
+}
+
+fn is_cached(path: &Path) -> bool {
+    fs::metadata(path).is_ok_and(|m| m.is_file() && m.len() > 0)
+}
+
+/// Download the input for `day` into `save_dir` unless one of `dirs` already
+/// has a non-empty copy. Inputs never change, so a saved input is never
+/// downloaded again. An empty input, as created by `new`, is replaced.
+fn fetch_input(day: u32, client: &Client, dirs: &[PathBuf], save_dir: &Path) -> Result<Fetched> {
+    if let Some(path) = dirs
+        .iter()
+        .map(|dir| dir.join(io::file_name(day)))
+        .find(|path| is_cached(path))
+    {
+        return Ok(Fetched::Cached(path));
+    }
+
+    let path = save_dir.join(io::file_name(day));
+    let input = client.input(day)?;
+
+    let io_error = |source| AocError::Io {
+        path: path.clone(),
+        source,
+    };
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(io_error)?;
+    }
+    fs::write(&path, input).map_err(io_error)?;
+
+    Ok(Fetched::Downloaded(path))
+}
+
+/// Download the input for `day` into the input directory of `config`.
+pub fn fetch(day: u32, config: &Config) -> bool {
+    let result = Client::from_config(config).and_then(|client| {
+        fetch_input(day, &client, &io::input_dirs(config), &io::save_dir(config))
+    });
+
+    match result {
+        Ok(Fetched::Cached(path)) => {
+            println!("Day {} input is already saved at {}", day, path.display());
+            true
+        }
+        Ok(Fetched::Downloaded(path)) => {
+            println!("Saved day {} input to {}", day, path.display());
+            true
+        }
+        Err(e) => {
+            eprintln!("error: {}", e);
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::client::stub;
+
+    #[test]
+    fn test_fetch_caches_input() {
+        let dir = std::env::temp_dir().join(format!("aoc22-fetch-{}", std::process::id()));
+        let dirs = [dir.clone()];
+        // `new` leaves an empty input, which is not a cached one.
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("day6.txt"), "").unwrap();
+
+        // The stub only answers once, so a second download would fail.
+        let (url, server) = stub::serve(vec![(200, "mjqjpqmgbljsphdztnvjfqwrcgsmlb\n")]);
+        let client = Client::new(url, "abc123");
+
+        let path = dir.join("day6.txt");
+        assert_eq!(
+            fetch_input(6, &client, &dirs, &dir).unwrap(),
+            Fetched::Downloaded(path.clone())
+        );
+        assert_eq!(
+            fetch_input(6, &client, &dirs, &dir).unwrap(),
+            Fetched::Cached(path.clone())
+        );
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "mjqjpqmgbljsphdztnvjfqwrcgsmlb\n"
+        );
+        assert_eq!(server.join().unwrap().len(), 1);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,203 @@
+//! `new`: scaffold the module, registry entry, input and examples of a day.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::error::{AocError, Result};
+
+const DAY_TEMPLATE: &str = include_str!("templates/day.rs");
+const EXAMPLES_TEMPLATE: &str = include_str!("templates/examples.toml");
+
+fn read(path: &Path) -> Result<String> {
+    fs::read_to_string(path).map_err(|source| AocError::Io {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+fn write(path: &Path, contents: &str) -> Result<()> {
+    let io_error = |source| AocError::Io {
+        path: path.to_path_buf(),
+        source,
+    };
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(io_error)?;
+    }
+    fs::write(path, contents).map_err(io_error)
+}
+
+/// The day number of a line such as `pub mod day5;` or
+/// `    Day::of::<day5::Day5>(),`.
+fn module_day(line: &str, prefix: &str, suffix: &str) -> Option<u32> {
+    line.trim()
+        .strip_prefix(prefix)?
+        .split(suffix)
+        .next()?
+        .parse()
+        .ok()
+}
+
+/// Insert `new` among the lines for which `day_of` returns a day, keeping
+/// them ordered. Returns false if there are no such lines.
+fn insert_ordered(
+    lines: &mut Vec<String>,
+    day: u32,
+    new: String,
+    day_of: impl Fn(&str) -> Option<u32>,
+) -> bool {
+    let days: Vec<(usize, u32)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, l)| Some((i, day_of(l)?)))
+        .collect();
+    let Some(&(last, _)) = days.last() else {
+        return false;
+    };
+    let at = days
+        .iter()
+        .find(|&&(_, d)| d > day)
+        .map_or(last + 1, |&(i, _)| i);
+    lines.insert(at, new);
+    true
+}
+
+/// Add `pub mod day{N};` and its entry in `DAYS` to the source of
+/// `src/solutions.rs`.
+fn register(source: &str, day: u32) -> Result<String> {
+    let unexpected = |what: &str| {
+        AocError::InvalidState(format!(
+            "Could not find {} in src/solutions.rs to register day {}",
+            what, day
+        ))
+    };
+    let mut lines: Vec<String> = source.lines().map(String::from).collect();
+
+    if !insert_ordered(&mut lines, day, format!("pub mod day{};", day), |l| {
+        module_day(l, "pub mod day", ";")
+    }) {
+        return Err(unexpected("the day modules"));
+    }
+    let entry = format!("    Day::of::<day{}::Day{}>(),", day, day);
+    if !insert_ordered(&mut lines, day, entry, |l| {
+        module_day(l, "Day::of::<day", "::")
+    }) {
+        return Err(unexpected("the DAYS registry"));
+    }
+
+    let mut source = lines.join("\n");
+    source.push('\n');
+    Ok(source)
+}
+
+/// Create the files for `day` in the crate at `root` and register it.
+/// Nothing is written if the day already has a module. An existing input is
+/// left alone, as it may already have been downloaded. Returns the paths
+/// created or changed.
+pub fn scaffold(root: &Path, day: u32) -> Result<Vec<PathBuf>> {
+    let module = root.join(format!("src/solutions/day{}.rs", day));
+    let registry = root.join("src/solutions.rs");
+    let input = root.join("src/inputs").join(format!("day{}.txt", day));
+    let examples = root.join(format!("src/inputs/examples/day{}.toml", day));
+
+    let source = read(&registry)?;
+    let declared = source
+        .lines()
+        .any(|l| module_day(l, "pub mod day", ";") == Some(day));
+    if module.exists() || declared {
+        return Err(AocError::InvalidState(format!(
+            "Day {} already exists, refusing to overwrite it",
+            day
+        )));
+    }
+    if examples.exists() {
+        return Err(AocError::InvalidState(format!(
+            "{} already exists, refusing to overwrite it",
+            examples.display()
+        )));
+    }
+    let registered = register(&source, day)?;
+
+    let fill = |template: &str| template.replace("{day}", &day.to_string());
+    write(&module, &fill(DAY_TEMPLATE))?;
+    write(&registry, &registered)?;
+    write(&examples, &fill(EXAMPLES_TEMPLATE))?;
+    let mut changed = vec![module, registry, examples];
+    if !input.exists() {
+        write(&input, "")?;
+        changed.push(input);
+    }
+
+    Ok(changed)
+}
+
+/// Scaffold `day` in this crate's source tree and print what changed.
+pub fn new(day: u32) -> bool {
+    match scaffold(Path::new(env!("CARGO_MANIFEST_DIR")), day) {
+        Ok(paths) => {
+            for path in paths {
+                println!("Wrote {}", path.display());
+            }
+            println!("\nRebuild to run day {}", day);
+            true
+        }
+        Err(e) => {
+            eprintln!("error: {}", e);
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOLUTIONS: &str = "\
+pub mod day1;
+pub mod day3;
+
+static DAYS: &[Day] = &[
+    Day::of::<day1::Day1>(),
+    Day::of::<day3::Day3>(),
+];
+";
+
+    #[test]
+    fn test_register_keeps_days_ordered() {
+        assert_eq!(
+            register(SOLUTIONS, 2).unwrap(),
+            "\
+pub mod day1;
+pub mod day2;
+pub mod day3;
+
+static DAYS: &[Day] = &[
+    Day::of::<day1::Day1>(),
+    Day::of::<day2::Day2>(),
+    Day::of::<day3::Day3>(),
+];
+"
+        );
+        assert!(register(SOLUTIONS, 4)
+            .unwrap()
+            .contains("day3::Day3>(),\n    Day::of::<day4::Day4>(),\n];"));
+    }
+
+    #[test]
+    fn test_scaffold_refuses_existing_day() {
+        let root = std::env::temp_dir().join(format!("aoc22-new-{}", std::process::id()));
+        write(&root.join("src/solutions.rs"), SOLUTIONS).unwrap();
+
+        let created = scaffold(&root, 2).unwrap();
+        assert_eq!(created.len(), 4);
+        let module = read(&root.join("src/solutions/day2.rs")).unwrap();
+        assert!(module.contains("impl Solution for Day2"));
+        assert!(!module.contains("{day}"));
+
+        assert!(scaffold(&root, 2).is_err());
+        assert!(scaffold(&root, 3).is_err());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
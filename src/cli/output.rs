@@ -0,0 +1,53 @@
+//! Output formats shared by the commands.
+//!
+//! In JSON mode every command prints a single document:
+//!
+//! ```json
+//! {"command": "run", "success": true, "results": [...]}
+//! ```
+//!
+//! where each result has at least `day`, `part`, `answer`, `duration_ns` and
+//! `error` fields. `part` is null for records about reading or parsing the
+//! input, and `answer`, `duration_ns` and `error` are null when they don't
+//! apply.
+
+use serde::Serialize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+}
+
+impl TryFrom<&str> for Format {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(format!("Invalid format {:?}, expected text or json", value)),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct Document<'a, T> {
+    command: &'a str,
+    success: bool,
+    results: &'a [T],
+}
+
+/// Print the results of `command` as a JSON document on stdout.
+pub fn print_json<T: Serialize>(command: &str, success: bool, results: &[T]) {
+    let document = Document {
+        command,
+        success,
+        results,
+    };
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&document).expect("Results are always serializable")
+    );
+}
@@ -0,0 +1,275 @@
+//! `replay`: animate the day 5 crane in the terminal, one move at a time,
+//! with the crates it just put down highlighted.
+//!
+//! Controls are typed as a line and entered while the replay runs, so no
+//! terminal raw mode is needed:
+//!
+//! - Enter or `n`: step forward one move
+//! - `b`: step back one move
+//! - `g N` or `N`: go to the arrangement after move N, 0 being the start
+//! - `p`: play or pause
+//! - `+` / `-`: play twice as fast / slow
+//! - `q`: quit
+
+use std::{
+    io::{self, BufRead},
+    sync::mpsc::{self, Receiver, RecvTimeoutError},
+    thread,
+    time::Duration,
+};
+
+use crate::{
+    cli::crane::{self, Crane},
+    config::Config,
+    error::AocError,
+    solutions::day5::{Movement, Stacks},
+    utils::io::Source,
+};
+
+const CLEAR: &str = "\x1b[2J\x1b[H";
+const HIGHLIGHT: &str = "\x1b[1;33m";
+const RESET: &str = "\x1b[0m";
+
+const CONTROLS: &str =
+    "Enter/n: step  b: back  g N: go to move N  p: play/pause  +/-: speed  q: quit";
+
+/// The fastest playback allowed.
+const MIN_INTERVAL: Duration = Duration::from_millis(10);
+/// The slowest playback allowed.
+const MAX_INTERVAL: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Control {
+    Step,
+    Back,
+    GoTo(usize),
+    PlayPause,
+    Faster,
+    Slower,
+    Quit,
+}
+
+impl TryFrom<&str> for Control {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let value = value.trim();
+        let target = value.strip_prefix('g').unwrap_or(value).trim();
+        match value {
+            "" | "n" => Ok(Control::Step),
+            "b" => Ok(Control::Back),
+            "p" => Ok(Control::PlayPause),
+            "+" => Ok(Control::Faster),
+            "-" => Ok(Control::Slower),
+            "q" => Ok(Control::Quit),
+            _ => target
+                .parse()
+                .map(Control::GoTo)
+                .map_err(|_| format!("Unknown control {:?}", value)),
+        }
+    }
+}
+
+/// Where the replay is and how it is playing.
+#[derive(Debug, PartialEq, Eq)]
+struct Player {
+    /// The number of moves made.
+    position: usize,
+    /// The number of moves that can be made.
+    last: usize,
+    playing: bool,
+    interval: Duration,
+}
+
+impl Player {
+    /// Apply `control`. Returns false to quit.
+    fn apply(&mut self, control: Control) -> bool {
+        match control {
+            Control::Step => self.go_to(self.position + 1),
+            Control::Back => self.go_to(self.position.saturating_sub(1)),
+            Control::GoTo(position) => self.go_to(position),
+            Control::PlayPause if !self.playing && self.position == self.last => {
+                self.position = 0;
+                self.playing = true;
+            }
+            Control::PlayPause => self.playing = !self.playing,
+            Control::Faster => self.interval = (self.interval / 2).max(MIN_INTERVAL),
+            Control::Slower => self.interval = self.interval.saturating_mul(2).min(MAX_INTERVAL),
+            Control::Quit => return false,
+        }
+        true
+    }
+
+    /// Pause at `position`, or at the last move if it is past the end.
+    fn go_to(&mut self, position: usize) {
+        self.position = position.min(self.last);
+        self.playing = false;
+    }
+
+    /// Make the next move while playing, pausing at the end.
+    fn tick(&mut self) {
+        if self.playing {
+            self.position = (self.position + 1).min(self.last);
+            self.playing = self.position < self.last;
+        }
+    }
+}
+
+/// Draw the arrangement after `position` moves, highlighting the crates the
+/// last of them put down.
+fn frame(arrangements: &[Stacks], moves: &[Movement], player: &Player) -> String {
+    let stacks = &arrangements[player.position];
+    let (title, moved) = match player.position.checked_sub(1) {
+        Some(i) => (
+            format!("Move {}/{}: {}", player.position, moves.len(), moves[i]),
+            stacks.moved_crates(&moves[i]),
+        ),
+        None => (format!("Start, {} moves", moves.len()), Vec::new()),
+    };
+    let drawing = stacks.draw(|stack, level, text| {
+        if moved.contains(&(stack, level)) {
+            format!("{}{}{}", HIGHLIGHT, text, RESET)
+        } else {
+            text
+        }
+    });
+    let status = if player.playing {
+        format!("Playing, {:?} per move", player.interval)
+    } else {
+        "Paused".to_string()
+    };
+
+    format!("{}\n\n{}\n\n{}", title, drawing, status)
+}
+
+/// Read controls from stdin on another thread, so they can be waited for
+/// with a timeout while playing.
+fn controls() -> Receiver<String> {
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        for line in io::stdin().lock().lines() {
+            let Ok(line) = line else { break };
+            if sender.send(line).is_err() {
+                break;
+            }
+        }
+    });
+    receiver
+}
+
+/// Show each arrangement in turn, making a move every `interval` unless
+/// starting paused with `step`. `error` is shown after the last arrangement.
+fn play(
+    arrangements: &[Stacks],
+    moves: &[Movement],
+    error: Option<&AocError>,
+    interval: Duration,
+    step: bool,
+) {
+    let mut player = Player {
+        position: 0,
+        last: arrangements.len() - 1,
+        playing: !step,
+        interval,
+    };
+    let controls = controls();
+    let mut message = None;
+
+    loop {
+        println!("{}{}", CLEAR, frame(arrangements, moves, &player));
+        if let (Some(e), true) = (error, player.position == player.last) {
+            println!("\nerror: {}", e);
+        }
+        if let Some(message) = message.take() {
+            println!("\n{}", message);
+        }
+        println!("\n{}", CONTROLS);
+
+        let line = if player.playing {
+            controls.recv_timeout(player.interval)
+        } else {
+            controls.recv().map_err(|_| RecvTimeoutError::Disconnected)
+        };
+        match line {
+            Ok(line) => match Control::try_from(line.as_str()) {
+                Ok(control) if !player.apply(control) => return,
+                Ok(_) => {}
+                Err(e) => message = Some(e),
+            },
+            Err(RecvTimeoutError::Timeout) => player.tick(),
+            // Stdin is closed, so play to the end without waiting for
+            // controls.
+            Err(RecvTimeoutError::Disconnected) if player.playing => {
+                thread::sleep(player.interval);
+                player.tick();
+            }
+            Err(RecvTimeoutError::Disconnected) => return,
+        }
+    }
+}
+
+/// Replay day 5 with `crane`. Returns whether every move could be made.
+pub fn replay(
+    crane: Crane,
+    interval: Duration,
+    step: bool,
+    source: &Source,
+    config: &Config,
+) -> bool {
+    let (stacks, moves) = match crane::load(source, config) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            return false;
+        }
+    };
+    let (arrangements, error) = stacks.arrangements(&moves, crane.mover().as_ref());
+
+    play(&arrangements, &moves, error.as_ref(), interval, step);
+    error.is_none()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_player_controls() {
+        let mut player = Player {
+            position: 0,
+            last: 3,
+            playing: true,
+            interval: Duration::from_millis(100),
+        };
+        let control = |s: &str| Control::try_from(s).unwrap();
+
+        player.tick();
+        assert_eq!(player.position, 1);
+        assert!(player.apply(control("")));
+        assert_eq!((player.position, player.playing), (2, false));
+        player.apply(control("b"));
+        player.apply(control("b"));
+        player.apply(control("b"));
+        assert_eq!(player.position, 0);
+        player.apply(control("g 7"));
+        assert_eq!(player.position, 3);
+        player.apply(control("2"));
+        assert_eq!(player.position, 2);
+
+        player.apply(control("p"));
+        player.apply(control("+"));
+        assert_eq!(player.interval, Duration::from_millis(50));
+        for _ in 0..100 {
+            player.apply(control("-"));
+        }
+        assert_eq!(player.interval, MAX_INTERVAL);
+        player.interval = Duration::from_millis(50);
+        player.tick();
+        assert_eq!((player.position, player.playing), (3, false));
+        player.apply(control("p"));
+        assert_eq!((player.position, player.playing), (0, true));
+
+        assert!(Control::try_from("x").is_err());
+        assert!(!player.apply(control("q")));
+    }
+}
@@ -0,0 +1,180 @@
+//! `run`: solve the selected days and print each answer as it is found.
+
+use std::time::Duration;
+
+use serde::Serialize;
+
+use crate::{
+    cli::output::{self, Format},
+    config::Config,
+    error::{AocError, Result},
+    solutions::{self, Answer, Day, Part},
+    utils::{
+        io::{self, Source},
+        timing,
+    },
+};
+
+/// The result of parsing (`part` is `None`) or solving one part of a day.
+#[derive(Debug, Serialize)]
+struct Record {
+    day: u32,
+    part: Option<u8>,
+    answer: Option<Answer>,
+    duration_ns: Option<u128>,
+    error: Option<String>,
+}
+
+impl Record {
+    fn ok(day: &Day, part: Option<Part>, answer: Option<Answer>, duration: Duration) -> Self {
+        Self {
+            day: day.day,
+            part: part.map(Part::number),
+            answer,
+            duration_ns: Some(duration.as_nanos()),
+            error: None,
+        }
+    }
+
+    /// A failure to read or parse (`part` is `None`) or to solve a part.
+    fn error(day: &Day, part: Option<Part>, error: &AocError) -> Self {
+        Self {
+            day: day.day,
+            part: part.map(Part::number),
+            answer: None,
+            duration_ns: None,
+            error: Some(error.to_string()),
+        }
+    }
+
+    fn print(&self) {
+        match (&self.error, self.part, &self.answer, self.duration_ns) {
+            (Some(e), Some(part), _, _) => {
+                eprintln!("error: day {} part {}: {}", self.day, part, e)
+            }
+            (Some(e), None, _, _) => eprintln!("error: day {}: {}", self.day, e),
+            (None, None, _, Some(ns)) => {
+                println!("Parse  :: {:?}", Duration::from_nanos(ns as u64))
+            }
+            (None, Some(part), Some(answer), Some(ns)) => println!(
+                "Part {} :: {} ({:?})",
+                part,
+                answer,
+                Duration::from_nanos(ns as u64)
+            ),
+            _ => {}
+        }
+    }
+}
+
+/// Run a single day, passing each record to `emit` as it is produced.
+/// Returns whether every part was solved, or the error if the input could
+/// not be read or parsed.
+fn run_day(
+    day: &Day,
+    part: Option<Part>,
+    source: &Source,
+    config: &Config,
+    emit: &mut impl FnMut(Record),
+) -> Result<bool> {
+    let raw = io::read(day.day, source, config)?;
+
+    let (input, elapsed) = timing::time(|| day.parse_input(&raw));
+    let input = input?;
+    emit(Record::ok(day, None, None, elapsed));
+
+    // Each part is solved even if the other fails.
+    let mut solved = true;
+    for p in Part::BOTH
+        .into_iter()
+        .filter(|&p| part.is_none_or(|part| part == p))
+    {
+        let (answer, elapsed) = timing::time(|| day.solve(p, &input));
+        match answer {
+            Ok(answer) => emit(Record::ok(day, Some(p), Some(answer), elapsed)),
+            Err(e) => {
+                emit(Record::error(day, Some(p), &e));
+                solved = false;
+            }
+        }
+    }
+
+    Ok(solved)
+}
+
+/// Run every selected day, reporting errors without stopping the remaining
+/// days. Text output is printed as each answer is found; JSON output once
+/// every day has finished. Returns whether all days succeeded.
+pub fn run(
+    days: &[u32],
+    part: Option<Part>,
+    source: &Source,
+    format: Format,
+    config: &Config,
+) -> bool {
+    let mut records = Vec::new();
+    let mut success = true;
+
+    for day in days.iter().filter_map(|&d| solutions::get(d)) {
+        if format == Format::Text {
+            println!("Day {} :: {}", day.day, day.title);
+        }
+        let mut emit = |record: Record| match format {
+            Format::Text => record.print(),
+            Format::Json => records.push(record),
+        };
+
+        match run_day(day, part, source, config, &mut emit) {
+            Ok(solved) => success &= solved,
+            Err(e) => {
+                emit(Record::error(day, None, &e));
+                success = false;
+            }
+        }
+    }
+
+    if format == Format::Json {
+        output::print_json("run", success, &records);
+    }
+
+    success
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The part of each record from running day 6 on `input`, and whether it
+    /// is an error.
+    fn run_parts(input: &str) -> Vec<(Option<u8>, bool)> {
+        let path = std::env::temp_dir().join(format!("aoc22-run-{}.txt", std::process::id()));
+        std::fs::write(&path, input).unwrap();
+
+        let mut records = Vec::new();
+        let day = solutions::get(6).unwrap();
+        let source = Source::File(path.clone());
+        let solved = run_day(day, None, &source, &Config::default(), &mut |r| {
+            records.push(r)
+        });
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(!solved.unwrap());
+        records
+            .iter()
+            .map(|r| (r.part, r.error.is_some()))
+            .collect()
+    }
+
+    #[test]
+    fn test_part_failures_name_the_part() {
+        assert_eq!(
+            run_parts("abcd\n"),
+            vec![(None, false), (Some(1), false), (Some(2), true)]
+        );
+        // No marker for either part, and part 2 is still attempted.
+        assert_eq!(
+            run_parts("aaaa\n"),
+            vec![(None, false), (Some(1), true), (Some(2), true)]
+        );
+    }
+}
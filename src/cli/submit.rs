@@ -0,0 +1,138 @@
+//! `submit`: solve one part of a day and submit the answer, unless earlier
+//! guesses already rule it out.
+
+use std::time::Duration;
+
+use crate::{
+    config::Config,
+    error::Result,
+    solutions::{self, Answer, Day, Part},
+    utils::{
+        client::{Client, Verdict},
+        guesses::{Guesses, PartGuesses},
+        io::{self, Source},
+    },
+};
+
+#[derive(Debug, PartialEq, Eq)]
+enum Submission {
+    /// Not submitted, for this reason.
+    RuledOut(String),
+    Judged(Verdict),
+}
+
+/// Submit `answer` unless `guesses` rule it out, recording the verdict.
+fn submit_answer(
+    client: &Client,
+    day: u32,
+    part: Part,
+    answer: &Answer,
+    guesses: &mut PartGuesses,
+) -> Result<Submission> {
+    if let Some(reason) = guesses.rule_out(answer) {
+        return Ok(Submission::RuledOut(reason));
+    }
+
+    let verdict = client.submit(day, part, answer)?;
+    guesses.record(answer, &verdict);
+    Ok(Submission::Judged(verdict))
+}
+
+fn format_wait(wait: Duration) -> String {
+    let seconds = wait.as_secs();
+    match seconds / 60 {
+        0 => format!("{}s", seconds),
+        minutes => format!("{}m {}s", minutes, seconds % 60),
+    }
+}
+
+/// Solve `part` of `day` from its real input and submit the answer,
+/// saving the verdict to the guesses file.
+fn solve_and_submit(day: &Day, part: Part, config: &Config) -> Result<Submission> {
+    let raw = io::read(day.day, &Source::Search, config)?;
+    let answer = day.solve(part, &day.parse_input(&raw)?)?;
+    println!("Day {} part {} :: {}", day.day, part, answer);
+
+    let client = Client::from_config(config)?;
+    let path = Guesses::path(config);
+    let mut guesses = Guesses::load(&path)?;
+    let submission = submit_answer(
+        &client,
+        day.day,
+        part,
+        &answer,
+        guesses.part_mut(day.day, part),
+    )?;
+    if matches!(submission, Submission::Judged(_)) {
+        guesses.save(&path)?;
+    }
+
+    Ok(submission)
+}
+
+/// Submit the answer to `part` of `day`. Returns whether it was accepted.
+pub fn submit(day: u32, part: Part, config: &Config) -> bool {
+    let Some(day) = solutions::get(day) else {
+        return false;
+    };
+
+    match solve_and_submit(day, part, config) {
+        Ok(Submission::RuledOut(reason)) => {
+            eprintln!("Not submitted: {}", reason);
+            false
+        }
+        Ok(Submission::Judged(verdict)) => {
+            match &verdict {
+                Verdict::Correct => println!("Correct!"),
+                Verdict::TooHigh => println!("Wrong, too high"),
+                Verdict::TooLow => println!("Wrong, too low"),
+                Verdict::Wrong => println!("Wrong"),
+                Verdict::Wait(wait) => println!(
+                    "Submitted too recently, try again in {}",
+                    format_wait(*wait)
+                ),
+                Verdict::AlreadySolved => {
+                    println!("Part {} is already solved or not unlocked yet", part)
+                }
+                Verdict::Unknown(text) => println!("Unrecognised response: {}", text),
+            }
+            verdict == Verdict::Correct
+        }
+        Err(e) => {
+            eprintln!("error: {}", e);
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::client::stub;
+
+    #[test]
+    fn test_submit_skips_ruled_out_guesses() {
+        let (url, server) = stub::serve(vec![
+            (
+                200,
+                "<article><p>That's not the right answer; your answer is too high.</p></article>",
+            ),
+            (200, "<article><p>That's the right answer!</p></article>"),
+        ]);
+        let client = Client::new(url, "abc123");
+        let mut guesses = PartGuesses::default();
+        let mut submit = |answer: i64| {
+            submit_answer(&client, 4, Part::Two, &answer.into(), &mut guesses).unwrap()
+        };
+
+        assert_eq!(submit(950), Submission::Judged(Verdict::TooHigh));
+        assert!(matches!(submit(950), Submission::RuledOut(_)));
+        assert!(matches!(submit(1000), Submission::RuledOut(_)));
+        assert_eq!(submit(919), Submission::Judged(Verdict::Correct));
+
+        let requests = server.join().unwrap();
+        assert_eq!(requests.len(), 2);
+        assert!(requests[0].starts_with("POST /day/4/answer HTTP/1.1\r\n"));
+        assert!(requests[0].ends_with("level=2&answer=950"));
+    }
+}
@@ -0,0 +1,99 @@
+//! Aligned plain-text tables for command output.
+
+use std::fmt;
+
+/// A table whose columns are padded to their widest cell. Columns listed as
+/// right-aligned are padded on the left, for numbers.
+pub struct Table {
+    headers: Vec<String>,
+    rows: Vec<Vec<String>>,
+    right_aligned: Vec<usize>,
+}
+
+impl Table {
+    pub fn new(headers: &[&str]) -> Self {
+        Self {
+            headers: headers.iter().map(|h| h.to_string()).collect(),
+            rows: Vec::new(),
+            right_aligned: Vec::new(),
+        }
+    }
+
+    pub fn align_right(mut self, columns: &[usize]) -> Self {
+        self.right_aligned.extend(columns);
+        self
+    }
+
+    pub fn row(&mut self, cells: Vec<String>) {
+        self.rows.push(cells);
+    }
+
+    fn widths(&self) -> Vec<usize> {
+        let mut widths: Vec<usize> = self.headers.iter().map(|h| h.chars().count()).collect();
+        for row in &self.rows {
+            for (i, cell) in row.iter().enumerate() {
+                let width = cell.chars().count();
+                match widths.get_mut(i) {
+                    Some(w) => *w = (*w).max(width),
+                    None => widths.push(width),
+                }
+            }
+        }
+
+        widths
+    }
+
+    fn write_row(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        cells: &[String],
+        widths: &[usize],
+    ) -> fmt::Result {
+        let line = cells
+            .iter()
+            .zip(widths)
+            .enumerate()
+            .map(|(i, (cell, &width))| {
+                if self.right_aligned.contains(&i) {
+                    format!("{:>width$}", cell, width = width)
+                } else {
+                    format!("{:<width$}", cell, width = width)
+                }
+            })
+            .collect::<Vec<String>>()
+            .join("  ");
+
+        writeln!(f, "{}", line.trim_end())
+    }
+}
+
+impl fmt::Display for Table {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let widths = self.widths();
+        self.write_row(f, &self.headers, &widths)?;
+        let rule: Vec<String> = widths.iter().map(|&w| "-".repeat(w)).collect();
+        self.write_row(f, &rule, &widths)?;
+        for row in &self.rows {
+            self.write_row(f, row, &widths)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_table_alignment() {
+        let mut table = Table::new(&["Day", "Answer"]).align_right(&[0]);
+        table.row(vec!["5".to_string(), "CMZ".to_string()]);
+        table.row(vec!["12".to_string(), "24000".to_string()]);
+
+        assert_eq!(
+            table.to_string(),
+            "Day  Answer\n---  ------\n  5  CMZ\n 12  24000\n"
+        );
+    }
+}
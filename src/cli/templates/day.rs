@@ -0,0 +1,42 @@
+use crate::{
+    error::{AocError, Result},
+    solutions::{Answer, Solution},
+};
+
+pub struct Day{day};
+
+impl Solution for Day{day} {
+    const DAY: u32 = {day};
+    const TITLE: &'static str = "Day {day}";
+
+    /// The lines of the puzzle input.
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.lines().map(String::from).collect())
+    }
+
+    fn part1(_input: &Vec<String>) -> Result<Answer> {
+        Err(AocError::NoSolution(
+            "Part 1 is not implemented".to_string(),
+        ))
+    }
+
+    fn part2(_input: &Vec<String>) -> Result<Answer> {
+        Err(AocError::NoSolution(
+            "Part 2 is not implemented".to_string(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "";
+
+    #[test]
+    fn test_parse_example() {
+        assert!(Day{day}::parse(EXAMPLE).is_ok());
+    }
+}
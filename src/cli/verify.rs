@@ -0,0 +1,147 @@
+//! `verify`: check the answers for the real inputs against the answers file.
+
+use serde::Serialize;
+
+use crate::{
+    cli::{
+        output::{self, Format},
+        table::Table,
+    },
+    config::Config,
+    solutions::{self, Answer, Part},
+    utils::{
+        answers::Answers,
+        io::{self, Source},
+        timing,
+    },
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+enum Status {
+    Pass,
+    Fail,
+    Missing,
+    Error,
+}
+
+impl Status {
+    fn label(&self) -> &'static str {
+        match self {
+            Status::Pass => "pass",
+            Status::Fail => "FAIL",
+            Status::Missing => "missing",
+            Status::Error => "ERROR",
+        }
+    }
+}
+
+fn status(actual: &Result<Answer, String>, expected: Option<&Answer>) -> Status {
+    match (actual, expected) {
+        (Err(_), _) => Status::Error,
+        (Ok(_), None) => Status::Missing,
+        (Ok(actual), Some(expected)) if actual == expected => Status::Pass,
+        (Ok(_), Some(_)) => Status::Fail,
+    }
+}
+
+/// The outcome of checking one part of a day.
+#[derive(Debug, Serialize)]
+struct Record {
+    day: u32,
+    part: u8,
+    status: Status,
+    answer: Option<Answer>,
+    expected: Option<Answer>,
+    duration_ns: Option<u128>,
+    error: Option<String>,
+}
+
+/// Solve both parts of every selected day and compare them to the known
+/// answers. Returns false if any answer is wrong or any day fails; days
+/// without a known answer are reported but do not fail verification.
+pub fn verify(days: &[u32], format: Format, config: &Config) -> bool {
+    let answers = match Answers::load(config) {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            return false;
+        }
+    };
+
+    let mut records = Vec::new();
+    for day in days.iter().filter_map(|&d| solutions::get(d)) {
+        let input =
+            io::read(day.day, &Source::Search, config).and_then(|raw| day.parse_input(&raw));
+
+        for part in Part::BOTH {
+            let (actual, duration) = match &input {
+                Ok(input) => {
+                    let (answer, elapsed) = timing::time(|| day.solve(part, input));
+                    (answer.map_err(|e| e.to_string()), Some(elapsed))
+                }
+                Err(e) => (Err(e.to_string()), None),
+            };
+            let expected = answers.get(day.day, part);
+
+            records.push(Record {
+                day: day.day,
+                part: part.number(),
+                status: status(&actual, expected),
+                expected: expected.cloned(),
+                duration_ns: duration.map(|d| d.as_nanos()),
+                answer: actual.as_ref().ok().cloned(),
+                error: actual.err(),
+            });
+        }
+    }
+
+    let count = |status| records.iter().filter(|r| r.status == status).count();
+    let success = count(Status::Fail) == 0 && count(Status::Error) == 0;
+
+    match format {
+        Format::Json => output::print_json("verify", success, &records),
+        Format::Text => {
+            let mut table =
+                Table::new(&["Day", "Part", "Status", "Answer", "Expected"]).align_right(&[0, 1]);
+            for record in &records {
+                table.row(vec![
+                    record.day.to_string(),
+                    record.part.to_string(),
+                    record.status.label().to_string(),
+                    // Errors can span several lines, so they are printed
+                    // after the table.
+                    match (&record.answer, &record.error) {
+                        (Some(answer), _) => answer.to_string(),
+                        (None, Some(_)) => "ERROR".to_string(),
+                        (None, None) => String::new(),
+                    },
+                    record
+                        .expected
+                        .as_ref()
+                        .map(Answer::to_string)
+                        .unwrap_or_default(),
+                ]);
+            }
+
+            print!("{}", table);
+            println!(
+                "\n{} passed, {} failed, {} missing, {} errors",
+                count(Status::Pass),
+                count(Status::Fail),
+                count(Status::Missing),
+                count(Status::Error)
+            );
+            for r in records.iter().filter(|r| r.error.is_some()) {
+                eprintln!(
+                    "error: day {} part {}: {}",
+                    r.day,
+                    r.part,
+                    r.error.as_deref().unwrap_or_default()
+                );
+            }
+        }
+    }
+
+    success
+}
@@ -0,0 +1,250 @@
+//! `watch`: re-run a day against its examples and real input whenever one of
+//! its files changes.
+//!
+//! Files are polled rather than watched with OS notifications, so this works
+//! the same everywhere. Solutions are compiled into the binary, so a change to
+//! a day's source is reported but only takes effect once `watch` is rebuilt
+//! and restarted.
+
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use crate::{
+    config::Config,
+    error::Result,
+    solutions::{self, Answer, Day, Parsed, Part},
+    utils::{
+        examples,
+        io::{self, Source},
+    },
+};
+
+/// Which input an outcome was computed from.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Target {
+    Example(usize),
+    Input,
+}
+
+impl Target {
+    fn label(&self) -> String {
+        match self {
+            Target::Example(i) => format!("Example {}", i + 1),
+            Target::Input => "Input".to_string(),
+        }
+    }
+}
+
+/// The answer (or error) for one part of one target.
+#[derive(Debug)]
+struct Outcome {
+    target: Target,
+    part: Part,
+    result: Result<Answer, String>,
+    expected: Option<Answer>,
+}
+
+type Answers = HashMap<(Target, Part), Result<Answer, String>>;
+
+fn outcomes(
+    day: &Day,
+    target: Target,
+    input: Result<Parsed>,
+    expected: [Option<Answer>; 2],
+) -> Vec<Outcome> {
+    let input = input.map_err(|e| e.to_string());
+    Part::BOTH
+        .into_iter()
+        .zip(expected)
+        .map(|(part, expected)| Outcome {
+            target: target.clone(),
+            part,
+            result: match &input {
+                Ok(input) => day.solve(part, input).map_err(|e| e.to_string()),
+                Err(e) => Err(e.clone()),
+            },
+            expected,
+        })
+        .collect()
+}
+
+/// Solve both parts of every example and of the real input. Files that cannot
+/// be read are reported once rather than per part.
+fn evaluate(day: &Day, config: &Config) -> (Vec<Outcome>, Vec<String>) {
+    let mut results = Vec::new();
+    let mut errors = Vec::new();
+
+    match examples::load(day.day, config) {
+        Ok(examples) => {
+            for (i, example) in examples.into_iter().enumerate() {
+                let expected = [example.part1, example.part2];
+                let input = day.parse(&example.input);
+                results.extend(outcomes(day, Target::Example(i), input, expected));
+            }
+        }
+        Err(e) => errors.push(format!("examples: {}", e)),
+    }
+    match io::read(day.day, &Source::Search, config) {
+        Ok(raw) => {
+            let input = day.parse_input(&raw);
+            results.extend(outcomes(day, Target::Input, input, [None, None]))
+        }
+        Err(e) => errors.push(format!("input: {}", e)),
+    }
+
+    (results, errors)
+}
+
+fn show(result: &Result<Answer, String>) -> String {
+    match result {
+        Ok(answer) => answer.to_string(),
+        Err(e) => format!("error: {}", e),
+    }
+}
+
+/// Describe each outcome, comparing examples with their expected answers and
+/// every answer with the one from the previous run.
+fn report(results: &[Outcome], previous: &Answers) -> Vec<String> {
+    results
+        .iter()
+        .map(|outcome| {
+            let mut line = format!(
+                "{:<10} part {}  {}",
+                outcome.target.label(),
+                outcome.part,
+                show(&outcome.result)
+            );
+            match (&outcome.result, &outcome.expected) {
+                (Ok(actual), Some(expected)) if actual == expected => line.push_str("  [pass]"),
+                (_, Some(expected)) => line.push_str(&format!("  [FAIL, expected {}]", expected)),
+                (_, None) => {}
+            }
+            match previous.get(&(outcome.target.clone(), outcome.part)) {
+                Some(before) if before != &outcome.result => {
+                    line.push_str(&format!("  (was {})", show(before)))
+                }
+                _ => {}
+            }
+            line
+        })
+        .collect()
+}
+
+/// The files whose changes trigger a re-run: every candidate location of the
+/// input and examples, so that creating one in a directory searched earlier
+/// is noticed too.
+fn watched_files(day: u32, config: &Config) -> Vec<PathBuf> {
+    let mut files: Vec<PathBuf> = io::input_dirs(config)
+        .into_iter()
+        .map(|dir| dir.join(io::file_name(day)))
+        .collect();
+    files.extend(examples::paths(day, config));
+    files
+}
+
+fn source_file(day: u32) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join(format!("src/solutions/day{}.rs", day))
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+/// Modification times of `files`, `None` for files that don't exist.
+fn snapshot(files: &[PathBuf]) -> Vec<Option<SystemTime>> {
+    files.iter().map(|path| modified(path)).collect()
+}
+
+/// Run `day` now and again whenever its files change, polling every
+/// `interval`. Only returns if the day is not registered.
+pub fn watch(day: u32, interval: Duration, config: &Config) -> bool {
+    let Some(day) = solutions::get(day) else {
+        return false;
+    };
+
+    let files = watched_files(day.day, config);
+    let source = source_file(day.day);
+    let mut stamps = snapshot(&files);
+    let mut source_stamp = modified(&source);
+    let mut previous = Answers::new();
+
+    println!("Day {} :: {}", day.day, day.title);
+    loop {
+        let (results, errors) = evaluate(day, config);
+        for error in errors {
+            eprintln!("error: {}", error);
+        }
+        for line in report(&results, &previous) {
+            println!("{}", line);
+        }
+        previous = results
+            .into_iter()
+            .map(|outcome| ((outcome.target, outcome.part), outcome.result))
+            .collect();
+        println!("\nWatching for changes, press Ctrl-C to stop");
+
+        loop {
+            thread::sleep(interval);
+
+            let source_now = modified(&source);
+            if source_now != source_stamp {
+                source_stamp = source_now;
+                println!(
+                    "{} changed, rebuild and restart watch to use the new code",
+                    source.display()
+                );
+            }
+
+            let now = snapshot(&files);
+            let changed: Vec<_> = files
+                .iter()
+                .zip(stamps.iter().zip(&now))
+                .filter(|(_, (before, after))| before != after)
+                .map(|(path, _)| path.display().to_string())
+                .collect();
+            stamps = now;
+            if !changed.is_empty() {
+                println!("\n{} changed", changed.join(", "));
+                break;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_report_diffs_previous_answers() {
+        let outcome = |target, result, expected| Outcome {
+            target,
+            part: Part::One,
+            result,
+            expected,
+        };
+        let results = vec![
+            outcome(Target::Example(0), Ok("CMZ".into()), Some("CMZ".into())),
+            outcome(Target::Example(1), Ok(3.into()), Some(4.into())),
+            outcome(Target::Input, Ok(12.into()), None),
+        ];
+        let previous = Answers::from([
+            ((Target::Example(0), Part::One), Ok("CMZ".into())),
+            ((Target::Input, Part::One), Err("bad input".to_string())),
+        ]);
+
+        assert_eq!(
+            report(&results, &previous),
+            vec![
+                "Example 1  part 1  CMZ  [pass]",
+                "Example 2  part 1  3  [FAIL, expected 4]",
+                "Input      part 1  12  (was error: bad input)",
+            ]
+        );
+    }
+}
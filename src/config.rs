@@ -0,0 +1,77 @@
+//! User configuration, loaded from `aoc22.toml` in the working directory or
+//! from the file named by `AOC_CONFIG`.
+
+use std::{
+    env,
+    path::{Path, PathBuf},
+};
+
+use serde::Deserialize;
+
+use crate::{error::Result, utils::toml_file};
+
+/// Environment variable naming a config file to use instead of the default.
+pub const CONFIG_ENV: &str = "AOC_CONFIG";
+/// Config file looked for in the working directory.
+pub const CONFIG_FILE: &str = "aoc22.toml";
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Directory containing `day{N}.txt` input files.
+    pub input_dir: Option<PathBuf>,
+    /// File of known answers checked by `verify`.
+    pub answers: Option<PathBuf>,
+    /// File recording the answers submitted by `submit`.
+    pub guesses: Option<PathBuf>,
+    /// Session cookie for the puzzle website, overridden by `AOC_SESSION`.
+    pub session: Option<String>,
+    /// Base URL of the year's puzzles, `https://adventofcode.com/2022` by
+    /// default.
+    pub base_url: Option<String>,
+}
+
+impl Config {
+    /// Load the config named by `AOC_CONFIG`, falling back to `aoc22.toml`
+    /// in the working directory. A missing default file is not an error.
+    pub fn load() -> Result<Self> {
+        match env::var_os(CONFIG_ENV) {
+            Some(path) => Self::from_file(path),
+            None if Path::new(CONFIG_FILE).exists() => Self::from_file(CONFIG_FILE),
+            None => Ok(Self::default()),
+        }
+    }
+
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let mut config: Self = toml_file::load(path, "config")?;
+
+        // Relative paths in the file are relative to the file, not the
+        // working directory.
+        let base = path.parent().unwrap_or_else(|| Path::new(""));
+        config.input_dir = config.input_dir.map(|dir| base.join(dir));
+        config.answers = config.answers.map(|file| base.join(file));
+        config.guesses = config.guesses.map(|file| base.join(file));
+
+        Ok(config)
+    }
+
+    pub fn parse(text: &str) -> Result<Self> {
+        toml_file::parse(text, "config")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_config() {
+        assert_eq!(Config::parse("").unwrap(), Config::default());
+        assert_eq!(
+            Config::parse("input_dir = \"inputs\"").unwrap().input_dir,
+            Some(PathBuf::from("inputs"))
+        );
+        assert!(Config::parse("input = 3").is_err());
+    }
+}
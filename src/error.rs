@@ -0,0 +1,185 @@
+//! The error type shared by every day and utility in the crate.
+
+use std::{
+    fmt, io,
+    path::{Path, PathBuf},
+};
+
+pub type Result<T, E = AocError> = std::result::Result<T, E>;
+
+#[derive(Debug)]
+pub enum AocError {
+    /// An input file could not be read.
+    Io { path: PathBuf, source: io::Error },
+    /// No input file for `day` exists at any of the searched paths.
+    InputNotFound { day: u32, tried: Vec<PathBuf> },
+    /// The input is malformed. `line` and `column` are 1-based, and the
+    /// error covers `width` characters from `column`.
+    Parse {
+        line: usize,
+        column: usize,
+        width: usize,
+        message: String,
+        /// The file the input was read from, once known.
+        file: Option<PathBuf>,
+        /// The text of the line, once known.
+        snippet: Option<String>,
+    },
+    /// A request to the puzzle website failed.
+    Http { url: String, message: String },
+    /// A required setting is missing from the environment and config file.
+    Config(String),
+    /// The parsed input describes a state the puzzle does not allow.
+    InvalidState(String),
+    /// The input is well formed but has no answer.
+    NoSolution(String),
+}
+
+impl AocError {
+    pub fn parse(line: usize, column: usize, message: impl Into<String>) -> Self {
+        AocError::Parse {
+            line,
+            column,
+            width: 1,
+            message: message.into(),
+            file: None,
+            snippet: None,
+        }
+    }
+
+    /// Shift the position of a parse error by `lines` and `columns`. Parsers
+    /// of a single line or field report positions relative to their own
+    /// input; callers use this to make them relative to the whole file.
+    pub fn offset(mut self, lines: usize, columns: usize) -> Self {
+        if let AocError::Parse { line, column, .. } = &mut self {
+            *line += lines;
+            *column += columns;
+        }
+        self
+    }
+
+    /// Make a parse error cover `text`, starting from its column.
+    pub fn spanning(mut self, text: &str) -> Self {
+        if let AocError::Parse { width, .. } = &mut self {
+            *width = text.chars().count().max(1);
+        }
+        self
+    }
+
+    /// Attach the line of `source` that a parse error points to, so that it
+    /// is shown with the error.
+    pub fn with_source(mut self, source: &str) -> Self {
+        if let AocError::Parse { line, snippet, .. } = &mut self {
+            *snippet = source.lines().nth(line.saturating_sub(1)).map(String::from);
+        }
+        self
+    }
+
+    /// Attach the file a parse error occurred in.
+    pub fn in_file(mut self, path: &Path) -> Self {
+        if let AocError::Parse { file, .. } = &mut self {
+            *file = Some(path.to_path_buf());
+        }
+        self
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AocError::Io { path, source } => {
+                write!(f, "Unable to read {}: {}", path.display(), source)
+            }
+            AocError::InputNotFound { day, tried } => {
+                write!(f, "No input found for day {}, tried:", day)?;
+                for path in tried {
+                    write!(f, "\n  {}", path.display())?;
+                }
+                Ok(())
+            }
+            AocError::Parse {
+                line,
+                column,
+                width,
+                message,
+                file,
+                snippet,
+            } => {
+                write!(f, "Parse error at ")?;
+                if let Some(file) = file {
+                    write!(f, "{}:", file.display())?;
+                }
+                write!(f, "{}:{}: {}", line, column, message)?;
+
+                // Underline the error like a compiler diagnostic:
+                //   |
+                // 6 | move x from 1 to 2
+                //   |      ^
+                if let Some(snippet) = snippet {
+                    let gutter = " ".repeat(line.to_string().len());
+                    write!(f, "\n{} |", gutter)?;
+                    write!(f, "\n{} | {}", line, snippet.replace('\t', " "))?;
+                    write!(
+                        f,
+                        "\n{} | {}{}",
+                        gutter,
+                        " ".repeat(column.saturating_sub(1)),
+                        "^".repeat(*width)
+                    )?;
+                }
+                Ok(())
+            }
+            AocError::Http { url, message } => write!(f, "Request to {} failed: {}", url, message),
+            AocError::Config(message) => write!(f, "Configuration error: {}", message),
+            AocError::InvalidState(message) => write!(f, "Invalid puzzle state: {}", message),
+            AocError::NoSolution(message) => write!(f, "No solution found: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for AocError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AocError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// Helpers for tests elsewhere in the crate.
+#[cfg(test)]
+pub(crate) mod test_support {
+    use super::AocError;
+
+    /// The line and column of a parse error, panicking on any other error.
+    pub(crate) fn parse_position(error: &AocError) -> (usize, usize) {
+        match error {
+            AocError::Parse { line, column, .. } => (*line, *column),
+            other => panic!("Expected a parse error, got {:?}", other),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error_snippet() {
+        let source = "move 1 from 2 to 1\nmove xy from 1 to 2\n";
+        let error = AocError::parse(1, 1, "Could not parse \"xy\" as usize")
+            .spanning("xy")
+            .offset(1, 5)
+            .with_source(source)
+            .in_file(Path::new("day5.txt"));
+
+        assert_eq!(
+            error.to_string(),
+            "\
+Parse error at day5.txt:2:6: Could not parse \"xy\" as usize
+  |
+2 | move xy from 1 to 2
+  |      ^^"
+        );
+    }
+}
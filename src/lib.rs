@@ -1,2 +1,7 @@
+pub mod cli;
+pub mod config;
+pub mod error;
 pub mod solutions;
-mod utils;
+pub mod utils;
+
+pub use error::{AocError, Result};
@@ -1,6 +1,5 @@
-use aoc22::solutions::day6::{solve_part1, solve_part2};
+use std::process::ExitCode;
 
-fn main() {
-    solve_part1();
-    solve_part2();
+fn main() -> ExitCode {
+    aoc22::cli::main(std::env::args().skip(1))
 }
@@ -1,6 +1,255 @@
+//! Solutions to each day's puzzle and a registry to look them up by day.
+
+use std::{any::Any, fmt};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::{AocError, Result},
+    utils::io::Input,
+};
+
 pub mod day1;
 pub mod day2;
 pub mod day3;
 pub mod day4;
 pub mod day5;
 pub mod day6;
+
+/// The answer to one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Int(i64),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+macro_rules! impl_answer_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::Int(i64::from(n))
+                }
+            }
+        )*
+    };
+}
+
+/// Integers that may not fit in an `i64`, so converting them can fail rather
+/// than giving a wrong answer.
+macro_rules! impl_answer_try_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl TryFrom<$t> for Answer {
+                type Error = AocError;
+
+                fn try_from(n: $t) -> Result<Self> {
+                    i64::try_from(n).map(Answer::Int).map_err(|_| {
+                        AocError::InvalidState(format!("Answer {} is too large", n))
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_answer_from_int!(i32, i64, u32);
+impl_answer_try_from_int!(u64, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+/// One of the two parts of a puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+/// A single day's puzzle. The input is parsed once by [`Solution::parse`] and
+/// the result is shared by both parts.
+pub trait Solution {
+    /// Day of the advent calendar the puzzle belongs to.
+    const DAY: u32;
+    /// Title of the puzzle.
+    const TITLE: &'static str;
+
+    /// The parsed puzzle input.
+    type Input: Any + Send;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+    fn part1(input: &Self::Input) -> Result<Answer>;
+    fn part2(input: &Self::Input) -> Result<Answer>;
+
+    /// Every problem with the shape of `input`, rather than just the first.
+    /// Defaults to the error from [`Solution::parse`], if any.
+    fn check(input: &str) -> Vec<AocError> {
+        Self::parse(input).err().into_iter().collect()
+    }
+}
+
+/// Type-erased input produced by [`Day::parse`].
+pub type Parsed = Box<dyn Any + Send>;
+
+/// A registered [`Solution`], with its input type erased so every day can be
+/// stored together and called generically.
+pub struct Day {
+    pub day: u32,
+    pub title: &'static str,
+    parse: fn(&str) -> Result<Parsed>,
+    check: fn(&str) -> Vec<AocError>,
+    part1: fn(&Parsed) -> Result<Answer>,
+    part2: fn(&Parsed) -> Result<Answer>,
+}
+
+impl Day {
+    const fn of<S: Solution>() -> Self {
+        Self {
+            day: S::DAY,
+            title: S::TITLE,
+            parse: |input| Ok(Box::new(S::parse(input)?)),
+            check: S::check,
+            part1: |input| S::part1(downcast::<S>(input)?),
+            part2: |input| S::part2(downcast::<S>(input)?),
+        }
+    }
+
+    /// Parse `input`. Parse errors show the line of `input` they occurred on.
+    pub fn parse(&self, input: &str) -> Result<Parsed> {
+        (self.parse)(input).map_err(|e| e.with_source(input))
+    }
+
+    /// Parse an input read by [`read`](crate::utils::io::read), naming its file
+    /// in parse errors.
+    pub fn parse_input(&self, input: &Input) -> Result<Parsed> {
+        self.parse(&input.text).map_err(|e| e.in_file(&input.path))
+    }
+
+    /// Every problem found in an input read by
+    /// [`read`](crate::utils::io::read), with parse errors showing where they
+    /// occurred.
+    pub fn check(&self, input: &Input) -> Vec<AocError> {
+        (self.check)(&input.text)
+            .into_iter()
+            .map(|e| e.with_source(&input.text).in_file(&input.path))
+            .collect()
+    }
+
+    /// Solve part 1 using input returned by this day's [`Day::parse`].
+    pub fn part1(&self, input: &Parsed) -> Result<Answer> {
+        (self.part1)(input)
+    }
+
+    /// Solve part 2 using input returned by this day's [`Day::parse`].
+    pub fn part2(&self, input: &Parsed) -> Result<Answer> {
+        (self.part2)(input)
+    }
+
+    /// Solve `part` using input returned by this day's [`Day::parse`].
+    pub fn solve(&self, part: Part, input: &Parsed) -> Result<Answer> {
+        match part {
+            Part::One => self.part1(input),
+            Part::Two => self.part2(input),
+        }
+    }
+}
+
+fn downcast<S: Solution>(input: &Parsed) -> Result<&S::Input> {
+    input
+        .downcast_ref()
+        .ok_or_else(|| AocError::InvalidState(format!("Input was not parsed by day {}", S::DAY)))
+}
+
+/// Every implemented day, in order.
+static DAYS: &[Day] = &[
+    Day::of::<day1::Day1>(),
+    Day::of::<day2::Day2>(),
+    Day::of::<day3::Day3>(),
+    Day::of::<day4::Day4>(),
+    Day::of::<day5::Day5>(),
+    Day::of::<day6::Day6>(),
+];
+
+/// All registered days, ordered by day number.
+pub fn all() -> &'static [Day] {
+    DAYS
+}
+
+/// Look up a registered day by its number.
+pub fn get(day: u32) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry_is_ordered() {
+        let days: Vec<u32> = all().iter().map(|d| d.day).collect();
+        assert!(days.windows(2).all(|w| w[0] < w[1]));
+        assert_eq!(get(5).map(|d| d.title), Some("Supply Stacks"));
+        assert!(get(0).is_none());
+    }
+
+    #[test]
+    fn test_answer_display() {
+        assert_eq!(Answer::from(24000u32).to_string(), "24000");
+        assert_eq!(Answer::from("CMZ").to_string(), "CMZ");
+        assert_eq!(Answer::try_from(7usize).unwrap(), Answer::Int(7));
+    }
+
+    #[test]
+    fn test_answer_too_large() {
+        assert_eq!(
+            Answer::try_from(i64::MAX as u64).unwrap(),
+            Answer::Int(i64::MAX)
+        );
+        assert!(Answer::try_from(u64::MAX).is_err());
+    }
+
+    #[test]
+    fn test_input_from_another_day() {
+        let input = get(1).unwrap().parse("1\n").unwrap();
+        assert!(matches!(
+            get(2).unwrap().part1(&input),
+            Err(AocError::InvalidState(_))
+        ));
+    }
+}
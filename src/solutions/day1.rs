@@ -1,64 +1,99 @@
 use std::str::FromStr;
 
-use crate::utils;
+use crate::{
+    error::{AocError, Result},
+    solutions::{Answer, Solution},
+    utils::parse,
+};
 
 /// Food for the elves.
 struct Food {
-    food: u32,
+    food: u64,
 }
 
 impl FromStr for Food {
-    type Err = String;
+    type Err = AocError;
 
-    /// Parse food from a string where each `food` is separated by '\n'.
+    /// Parse food from a string where each `food` is separated by '\n'. The
+    /// total is a `u64` so that it cannot overflow.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let x = s
-            .trim()
-            .split('\n')
+        let food = parse::lines(s, parse::value::<u32>)?
             .into_iter()
-            .map(|l| l.parse::<u32>().unwrap())
-            .collect::<Vec<u32>>()
-            .iter()
+            .map(u64::from)
             .sum();
 
-        Ok(Food { food: x })
+        Ok(Food { food })
     }
 }
 
-impl From<Food> for u32 {
+impl From<Food> for u64 {
     fn from(f: Food) -> Self {
         f.food
     }
 }
 
-impl AsRef<u32> for Food {
-    fn as_ref(&self) -> &u32 {
+impl AsRef<u64> for Food {
+    fn as_ref(&self) -> &u64 {
         &self.food
     }
 }
 
-pub fn solve_part1() {
-    let input = utils::io::read(1);
-    let max: u32 = input
-        .trim()
-        .split("\n\n")
-        .map(|lines| lines.lines().map(|line| line.parse::<u32>().unwrap()).sum())
-        .max()
-        .unwrap();
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u32 = 1;
+    const TITLE: &'static str = "Calorie Counting";
+
+    /// The total calories carried by each elf.
+    type Input = Vec<u64>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse::groups(input, |group| group.parse::<Food>().map(u64::from))
+    }
+
+    fn check(input: &str) -> Vec<AocError> {
+        // Blank lines separate the elves, every other line is a calorie count.
+        parse::line_errors(input, |line| match line {
+            "" => Ok(0),
+            line => parse::value::<u32>(line),
+        })
+    }
+
+    fn part1(input: &Vec<u64>) -> Result<Answer> {
+        let max = *input
+            .iter()
+            .max()
+            .ok_or_else(|| AocError::NoSolution("No elves are carrying food".to_string()))?;
+
+        max.try_into()
+    }
+
+    fn part2(input: &Vec<u64>) -> Result<Answer> {
+        if input.is_empty() {
+            return Err(AocError::NoSolution(
+                "No elves are carrying food".to_string(),
+            ));
+        }
+        let mut sums = input.clone();
+        sums.sort_by(|a, b| a.cmp(b).reverse());
+        sums.truncate(3);
+        let max3: u64 = sums.iter().sum();
 
-    println!("Part 1 :: max value: {}", max);
+        max3.try_into()
+    }
 }
 
-pub fn solve_part2() {
-    let input = utils::io::read(1);
-    let mut sums: Vec<u32> = input
-        .trim()
-        .split("\n\n")
-        .map(|lines| lines.lines().map(|line| line.parse::<u32>().unwrap()).sum())
-        .collect::<Vec<u32>>();
-    sums.sort_by(|a, b| a.cmp(b).reverse());
-    sums.truncate(3);
-    let max3: u32 = sums.iter().sum();
-
-    println!("Part 2 :: max value: {}", max3);
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_large_totals() {
+        let input = Day1::parse("4294967295\n1\n\n4294967295\n\n5\n").unwrap();
+        assert_eq!(Day1::part1(&input).unwrap(), Answer::Int(4294967296));
+        assert_eq!(Day1::part2(&input).unwrap(), Answer::Int(8589934596));
+
+        assert!(Day1::part1(&Vec::new()).is_err());
+        assert!(Day1::part2(&Vec::new()).is_err());
+    }
 }
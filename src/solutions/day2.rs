@@ -1,4 +1,8 @@
-use crate::utils;
+use crate::{
+    error::{AocError, Result},
+    solutions::{Answer, Solution},
+    utils::parse,
+};
 
 #[derive(PartialEq, Clone, Copy)]
 enum RockPaperScissors {
@@ -8,7 +12,7 @@ enum RockPaperScissors {
 }
 
 impl TryFrom<char> for RockPaperScissors {
-    type Error = String;
+    type Error = AocError;
 
     fn try_from(value: char) -> Result<Self, Self::Error> {
         use RockPaperScissors::*;
@@ -16,9 +20,10 @@ impl TryFrom<char> for RockPaperScissors {
             'A' | 'X' => Ok(Rock),
             'B' | 'Y' => Ok(Paper),
             'C' | 'Z' => Ok(Scissors),
-            _ => Err(format!(
-                "Input {} is not a valid Rock/Paper/Scissors value.",
-                value
+            _ => Err(AocError::parse(
+                1,
+                1,
+                format!("Input {} is not a valid Rock/Paper/Scissors value.", value),
             )),
         }
     }
@@ -69,7 +74,7 @@ enum Outcome {
 }
 
 impl TryFrom<char> for Outcome {
-    type Error = String;
+    type Error = AocError;
 
     fn try_from(value: char) -> Result<Self, Self::Error> {
         use Outcome::*;
@@ -77,7 +82,11 @@ impl TryFrom<char> for Outcome {
             'X' => Ok(Loss),
             'Y' => Ok(Draw),
             'Z' => Ok(Win),
-            _ => Err(format!("Input {} is not a valid outcome value.", value)),
+            _ => Err(AocError::parse(
+                1,
+                1,
+                format!("Input {} is not a valid outcome value.", value),
+            )),
         }
     }
 }
@@ -99,25 +108,25 @@ struct Match2 {
     outcome: Outcome,
 }
 
-impl TryFrom<&str> for Match2 {
-    type Error = String;
+/// A line of the strategy guide. The meaning of the second column differs
+/// between the two parts, so it is kept as the raw `code`.
+pub struct Round {
+    opponent_move: RockPaperScissors,
+    code: char,
+}
+
+impl TryFrom<&str> for Round {
+    type Error = AocError;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        let opponent_move: RockPaperScissors = value
-            .chars()
-            .next()
-            .ok_or_else(|| "Missing opponent move".to_string())?
-            .try_into()?;
-
-        let outcome: Outcome = value
-            .chars()
-            .nth(2)
-            .ok_or_else(|| "Missing required outcome".to_string())?
-            .try_into()?;
-
-        Ok(Match2 {
+        let (opponent, code): (char, char) = parse::template("{} {}", value)?;
+        let opponent_move = RockPaperScissors::try_from(opponent)?;
+        // Both parts interpret the code, so check it is valid for either.
+        Outcome::try_from(code).map_err(|e| e.offset(0, 2))?;
+
+        Ok(Round {
             opponent_move,
-            outcome,
+            code,
         })
     }
 }
@@ -136,31 +145,49 @@ impl Match2 {
     }
 }
 
-pub fn solve_part1() {
-    let input = utils::io::read(2);
+pub struct Day2;
 
-    let score1: u32 = input
-        .lines()
-        .map(|l| Match {
-            // Can improve this probably with split and tuples and filter map
-            opponent_move: RockPaperScissors::try_from(l.to_string().chars().next().unwrap())
-                .unwrap(),
-            response: RockPaperScissors::try_from(l.to_string().chars().nth(2).unwrap()).unwrap(),
-        })
-        .map(|m| m.score())
-        .sum();
+impl Solution for Day2 {
+    const DAY: u32 = 2;
+    const TITLE: &'static str = "Rock Paper Scissors";
 
-    println!("Part 1 :: Score: {}", score1);
-}
+    type Input = Vec<Round>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse::lines(input, |line| Round::try_from(line))
+    }
 
-pub fn solve_part2() {
-    let input = utils::io::read(2);
+    fn check(input: &str) -> Vec<AocError> {
+        parse::line_errors(input, |line| Round::try_from(line))
+    }
 
-    let score2: u32 = input
-        .lines()
-        .map(|l| Match2::try_from(l).expect("Unable to parse line"))
-        .map(|m| m.score())
-        .sum();
+    fn part1(input: &Vec<Round>) -> Result<Answer> {
+        let score1 = input
+            .iter()
+            .map(|round| {
+                let m = Match {
+                    opponent_move: round.opponent_move,
+                    response: RockPaperScissors::try_from(round.code)?,
+                };
+                Ok(m.score())
+            })
+            .sum::<Result<u32>>()?;
+
+        Ok(score1.into())
+    }
 
-    println!("Part 2 :: Score: {}", score2);
+    fn part2(input: &Vec<Round>) -> Result<Answer> {
+        let score2 = input
+            .iter()
+            .map(|round| {
+                let m = Match2 {
+                    opponent_move: round.opponent_move,
+                    outcome: Outcome::try_from(round.code)?,
+                };
+                Ok(m.score())
+            })
+            .sum::<Result<u32>>()?;
+
+        Ok(score2.into())
+    }
 }
@@ -1,4 +1,8 @@
-use crate::utils;
+use crate::{
+    error::{AocError, Result},
+    solutions::{Answer, Solution},
+    utils::parse,
+};
 use itertools::Itertools;
 
 /// A `Rucksack` of two compartments, each containing items represented by a
@@ -26,7 +30,6 @@ impl<'a> Rucksack<'a> {
 
     pub fn _three_sacks_intersection(a: &str, b: &str, c: &str) -> Option<char> {
         a.chars()
-            .into_iter()
             .filter(|ch| b.contains(*ch))
             .find(|ch| c.contains(*ch))
     }
@@ -34,10 +37,42 @@ impl<'a> Rucksack<'a> {
     /// Parse two compartments and return the first item in both compartments,
     /// if any exist, returning Some(item). Otherwise None is returned.
     pub fn first_intersection(&self) -> Option<char> {
-        self.comp1
-            .chars()
-            .into_iter()
-            .find(|c| self.comp2.contains(*c))
+        self.comp1.chars().find(|c| self.comp2.contains(*c))
+    }
+}
+
+/// Every reason a line is not a rucksack of an even number of items, each an
+/// ASCII letter.
+fn rucksack_problems(line: &str) -> Vec<AocError> {
+    let mut problems: Vec<AocError> = line
+        .chars()
+        .enumerate()
+        .filter(|(_, c)| !c.is_ascii_alphabetic())
+        .map(|(i, c)| AocError::parse(1, i + 1, format!("Invalid item {:?}", c)))
+        .collect();
+    let items = line.chars().count();
+    if items == 0 || !items.is_multiple_of(2) {
+        problems.push(
+            AocError::parse(
+                1,
+                1,
+                format!(
+                    "Rucksack of {} items cannot be split into two compartments",
+                    items
+                ),
+            )
+            .spanning(line),
+        );
+    }
+
+    problems
+}
+
+/// Check that a line is a rucksack, failing with its first problem.
+fn rucksack(line: &str) -> Result<String> {
+    match rucksack_problems(line).into_iter().next() {
+        Some(problem) => Err(problem),
+        None => Ok(line.to_string()),
     }
 }
 
@@ -53,44 +88,96 @@ impl From<char> for Priority {
     }
 }
 
-pub fn solve_part1() {
-    let input = utils::io::read(3);
+pub struct Day3;
 
-    let score: u32 = input
-        .lines()
-        .map(|l| {
-            Rucksack::with(l)
-                .first_intersection()
-                .unwrap_or_else(|| panic!("No items in both compartments in line {}", l))
-        })
-        .map(|common| Priority::from(common).0 as u32)
-        .sum();
+impl Solution for Day3 {
+    const DAY: u32 = 3;
+    const TITLE: &'static str = "Rucksack Reorganization";
 
-    println!("Part 1 :: Score: {}", score);
-}
+    /// The contents of each rucksack, one per line.
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse::lines(input, rucksack)
+    }
+
+    fn check(input: &str) -> Vec<AocError> {
+        let mut problems = parse::line_problems(input, rucksack_problems);
+        let rucksacks = input.lines().count();
+        if !rucksacks.is_multiple_of(3) {
+            problems.push(AocError::InvalidState(format!(
+                "{} rucksacks cannot be split into groups of three",
+                rucksacks
+            )));
+        }
 
-pub fn solve_part2() {
-    let input = utils::io::read(3);
-
-    let chunks = input.lines().chunks(3);
-    let score: u32 = chunks
-        .into_iter()
-        .map(|mut chunk| {
-            let (a, b, c) = (
-                chunk.next().unwrap(),
-                chunk.next().unwrap(),
-                chunk.next().unwrap(),
-            );
-            Priority::from(
-                a.chars()
-                    .into_iter()
+        problems
+    }
+
+    fn part1(input: &Vec<String>) -> Result<Answer> {
+        let score = input
+            .iter()
+            .enumerate()
+            .map(|(i, l)| {
+                let common = Rucksack::with(l).first_intersection().ok_or_else(|| {
+                    AocError::NoSolution(format!(
+                        "No items in both compartments of rucksack {} ({})",
+                        i + 1,
+                        l
+                    ))
+                })?;
+                Ok(Priority::from(common).0 as u32)
+            })
+            .sum::<Result<u32>>()?;
+
+        Ok(score.into())
+    }
+
+    fn part2(input: &Vec<String>) -> Result<Answer> {
+        if !input.len().is_multiple_of(3) {
+            return Err(AocError::InvalidState(format!(
+                "{} rucksacks cannot be split into groups of three",
+                input.len()
+            )));
+        }
+
+        let chunks = input.iter().chunks(3);
+        let score = chunks
+            .into_iter()
+            .enumerate()
+            .map(|(group, mut chunk)| {
+                let (a, b, c) = (
+                    chunk.next().unwrap(),
+                    chunk.next().unwrap(),
+                    chunk.next().unwrap(),
+                );
+                let badge = a
+                    .chars()
                     .filter(|ch| b.contains(*ch))
                     .find(|ch| c.contains(*ch))
-                    .unwrap_or_else(|| panic!("No value in all three Rucksacks")),
-            )
-            .0 as u32
-        })
-        .sum();
+                    .ok_or_else(|| {
+                        AocError::NoSolution(format!(
+                            "No item in all three rucksacks of group {}",
+                            group + 1
+                        ))
+                    })?;
+                Ok(Priority::from(badge).0 as u32)
+            })
+            .sum::<Result<u32>>()?;
 
-    println!("Part 2 :: Score: {}", score);
+        Ok(score.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::test_support::parse_position;
+
+    #[test]
+    fn test_check_reports_every_problem() {
+        let problems = Day3::check("ab1\nvJrwpWtwJgWr\nx-y?zz\n");
+        let positions: Vec<_> = problems.iter().map(parse_position).collect();
+        assert_eq!(positions, vec![(1, 3), (1, 1), (3, 2), (3, 4)]);
+    }
 }
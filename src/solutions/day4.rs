@@ -1,29 +1,16 @@
-use crate::utils;
-use std::{fmt::Debug, str::FromStr};
+use crate::{
+    error::{AocError, Result},
+    solutions::{Answer, Solution},
+    utils::parse::{self, Capture},
+};
 
-struct Range<T> {
+/// An inclusive range of section IDs.
+pub struct Range<T> {
     start: T,
     end: T,
 }
 
-impl<T: Ord + FromStr + Debug> Range<T> {
-    /// Create a range from text formed as '{Start}-{End}'
-    fn parse(s: &str) -> Result<Self, String> {
-        let mut nums = s.split('-');
-        let start: T = nums
-            .next()
-            .ok_or_else(|| "Could not find start".to_string())?
-            .parse()
-            .map_err(|_| "Could not parse start".to_string())?;
-        let end = nums
-            .next()
-            .ok_or_else(|| "Could not find end".to_string())?
-            .parse()
-            .map_err(|_| "Could not parse end".to_string())?;
-
-        Ok(Self { start, end })
-    }
-
+impl<T: Ord> Range<T> {
     fn contains_range(&self, other: &Self) -> bool {
         self.start <= other.start && self.end >= other.end
     }
@@ -40,35 +27,96 @@ impl<T: Ord + FromStr + Debug> Range<T> {
     }
 }
 
-pub fn solve_part1() {
-    let input = utils::io::read(4);
+/// Parse a range from its `start` and `end`, which must not be after `end`.
+fn range(start: Capture, end: Capture) -> Result<Range<i32>> {
+    let range = Range {
+        start: start.parse()?,
+        end: end.parse()?,
+    };
+    if range.start > range.end {
+        return Err(AocError::parse(
+            1,
+            start.column,
+            format!("Range {}-{} ends before it starts", range.start, range.end),
+        )
+        .spanning(&format!("{}-{}", start.text, end.text)));
+    }
+
+    Ok(range)
+}
 
-    let score: i32 = input
-        .lines()
-        .map(|l| {
-            let mut ranges = l.split(',');
-            let first: Range<i32> = Range::parse(ranges.next().unwrap()).unwrap();
-            let second: Range<i32> = Range::parse(ranges.next().unwrap()).unwrap();
+/// Parse a line formed as '{Start}-{End},{Start}-{End}', checking both
+/// ranges so that every problem with the line is reported.
+fn checked_pair(line: &str) -> Result<(Range<i32>, Range<i32>), Vec<AocError>> {
+    let captures = parse::captures("{}-{},{}-{}", line).map_err(|e| vec![e])?;
+    let [a, b, c, d] = captures[..] else {
+        unreachable!("The template has four captures")
+    };
 
-            (first.contains_range(&second) || second.contains_range(&first)) as i32
-        })
-        .sum();
+    match (range(a, b), range(c, d)) {
+        (Ok(first), Ok(second)) => Ok((first, second)),
+        (first, second) => Err(first.err().into_iter().chain(second.err()).collect()),
+    }
+}
 
-    println!("Part 1 :: Score: {}", score);
+/// Parse a line formed as '{Start}-{End},{Start}-{End}'.
+fn pair(line: &str) -> Result<(Range<i32>, Range<i32>)> {
+    checked_pair(line).map_err(|mut problems| problems.remove(0))
 }
 
-pub fn solve_part2() {
-    let input = utils::io::read(4);
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u32 = 4;
+    const TITLE: &'static str = "Camp Cleanup";
+
+    /// The pair of section assignments on each line.
+    type Input = Vec<(Range<i32>, Range<i32>)>;
 
-    let score: i32 = input
-        .lines()
-        .map(|l| {
-            let mut ranges = l.split(',');
-            let first: Range<i32> = Range::parse(ranges.next().unwrap()).unwrap();
-            let second: Range<i32> = Range::parse(ranges.next().unwrap()).unwrap();
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse::lines(input, pair)
+    }
+
+    fn check(input: &str) -> Vec<AocError> {
+        parse::line_problems(input, |line| checked_pair(line).err().unwrap_or_default())
+    }
 
-            first.overlaps(&second) as i32
-        })
-        .sum();
-    println!("Part 2 :: Score: {}", score);
+    fn part1(input: &Vec<(Range<i32>, Range<i32>)>) -> Result<Answer> {
+        let score: i32 = input
+            .iter()
+            .map(|(first, second)| {
+                (first.contains_range(second) || second.contains_range(first)) as i32
+            })
+            .sum();
+
+        Ok(score.into())
+    }
+
+    fn part2(input: &Vec<(Range<i32>, Range<i32>)>) -> Result<Answer> {
+        let score: i32 = input
+            .iter()
+            .map(|(first, second)| first.overlaps(second) as i32)
+            .sum();
+
+        Ok(score.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::test_support::parse_position;
+
+    #[test]
+    fn test_parse_error_position() {
+        let error = Day4::parse("2-4,6-8\n2-3,4-x\n").err().unwrap();
+        assert_eq!(parse_position(&error), (2, 7));
+    }
+
+    #[test]
+    fn test_check_reports_every_problem() {
+        let problems = Day4::check("4-2,6-8\n2-3,4-x\n5-3,9-1\n");
+        let positions: Vec<_> = problems.iter().map(parse_position).collect();
+        assert_eq!(positions, vec![(1, 1), (2, 7), (3, 1), (3, 5)]);
+    }
 }
@@ -1,113 +1,247 @@
-use crate::utils;
+use std::{fmt, ops::Range};
 
-#[derive(Debug)]
+use crate::{
+    error::{AocError, Result},
+    solutions::{Answer, Solution},
+    utils::parse::{self, Grid},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 /// A collection of CrateStacks storing the Elve's supplies.
-struct Stacks {
+pub struct Stacks {
+    /// The number labelling each stack in the drawing.
+    ids: Vec<usize>,
     stacks: Vec<CrateStack>,
 }
 
 impl Stacks {
-    fn with_capacity(n: usize) -> Self {
+    fn new(ids: Vec<usize>) -> Self {
         Self {
-            stacks: (0..n).map(|_| CrateStack::default()).collect(),
+            stacks: ids.iter().map(|_| CrateStack::default()).collect(),
+            ids,
         }
     }
 
-    /// Add a row of crates to the respective Stacks
-    fn parse_row(&mut self, line: &str) -> Result<(), String> {
-        // why do we have to allocate?
-        let line = line.chars().collect::<Vec<char>>();
+    /// The index of the stack labelled `id`.
+    fn index_of(&self, id: usize) -> Option<usize> {
+        self.ids.iter().position(|&i| i == id)
+    }
+
+    /// The crate on top of each stack, or ' ' for an empty stack.
+    pub fn top_crates(&self) -> String {
+        self.stacks
+            .iter()
+            .map(|stack| stack.0.last().map_or(' ', |c| (*c).into()))
+            .collect()
+    }
 
-        for (column, i) in (0..line.len()).step_by(4).enumerate() {
-            match &line[i..i + 3] {
-                ['[', cargo, ']'] => {
-                    self.stacks.get_mut(column).unwrap().0.push((*cargo).into());
-                }
-                [' ', ' ', ' '] => {} // empty row is fine, do nothing
-                _ => return Err(format!("Could not parse element {}", "e")),
+    /// Add a row of crates to the stacks whose labels they sit above. The row
+    /// is `level` rows above the floor, so every crate must land on a stack
+    /// of that height.
+    fn parse_row(&mut self, row: &[char], labels: &[Label], level: usize) -> Result<()> {
+        for (start, word) in words(row) {
+            let error = |message: String| AocError::parse(1, start + 1, message).spanning(&word);
+            let ['[', cargo, ']'] = word.chars().collect::<Vec<_>>()[..] else {
+                return Err(error(format!("Could not parse element {:?}", word)));
+            };
+
+            let columns = start..start + 3;
+            let index = labels
+                .iter()
+                .position(|label| {
+                    label.columns.start < columns.end && columns.start < label.columns.end
+                })
+                .ok_or_else(|| error("Crate is not above a numbered stack".to_string()))?;
+            let stack = &mut self.stacks[index].0;
+            if stack.len() != level {
+                return Err(error(format!(
+                    "Crate {} is not on top of stack {}",
+                    cargo, labels[index].id
+                )));
             }
+            stack.push(cargo.into());
         }
 
         Ok(())
     }
 
-    fn move_crates_individually(&mut self, movement: &Movement) -> Result<(), String> {
-        // should add error checking
-        for _ in 0..movement.quantity {
-            let moved_crate = self
-                .stacks
-                .get_mut(movement.origin)
-                .unwrap()
-                .0
-                .pop()
-                .unwrap();
-            self.stacks
-                .get_mut(movement.destination)
-                .unwrap()
-                .0
-                .push(moved_crate);
+    /// The number of crates on each stack, as `id: height`.
+    fn heights(&self) -> String {
+        self.ids
+            .iter()
+            .zip(&self.stacks)
+            .map(|(id, stack)| format!("{}: {}", id, stack.0.len()))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    /// Make the `index`th move of the procedure with `crane`. Nothing is
+    /// moved if the move is invalid.
+    fn move_crates(
+        &mut self,
+        index: usize,
+        movement: &Movement,
+        crane: &dyn CrateMover,
+    ) -> Result<()> {
+        let error = |reason: String| {
+            AocError::InvalidState(format!(
+                "Move {} ({}) failed: {}. Stack heights were {}",
+                index + 1,
+                movement,
+                reason,
+                self.heights()
+            ))
+        };
+        let stack = |id: usize| {
+            self.index_of(id)
+                .ok_or_else(|| error(format!("there is no stack {}", id)))
+        };
+
+        let origin = stack(movement.origin)?;
+        let destination = stack(movement.destination)?;
+        let height = self.stacks[origin].0.len();
+        if movement.quantity > height {
+            return Err(error(format!(
+                "stack {} only has {} crates",
+                movement.origin, height
+            )));
         }
 
+        let lifted = self.stacks[origin].0.split_off(height - movement.quantity);
+        self.stacks[destination].0.extend(crane.unload(lifted));
+
         Ok(())
     }
 
-    fn move_crates_together(&mut self, movement: &Movement) -> Result<(), String> {
-        let mut moved_crates = Vec::with_capacity(movement.quantity);
-        for _ in 0..movement.quantity {
-            moved_crates.push(
-                self.stacks
-                    .get_mut(movement.origin)
-                    .unwrap()
-                    .0
-                    .pop()
-                    .unwrap(),
-            )
+    /// The stacks after carrying out every move with `crane`.
+    pub fn rearrange(&self, moves: &[Movement], crane: &dyn CrateMover) -> Result<Self> {
+        let mut stacks = self.clone();
+        for (i, movement) in moves.iter().enumerate() {
+            stacks.move_crates(i, movement, crane)?;
         }
-        for _ in 0..movement.quantity {
-            self.stacks
-                .get_mut(movement.destination)
-                .unwrap()
-                .0
-                .push(moved_crates.pop().unwrap())
+
+        Ok(stacks)
+    }
+
+    /// Every arrangement of the stacks while carrying out `moves` with
+    /// `crane`, starting with this one. Stops at the first invalid move,
+    /// returning its error too.
+    pub fn arrangements(
+        &self,
+        moves: &[Movement],
+        crane: &dyn CrateMover,
+    ) -> (Vec<Self>, Option<AocError>) {
+        let mut arrangements = vec![self.clone()];
+        for (i, movement) in moves.iter().enumerate() {
+            let mut stacks = arrangements[i].clone();
+            if let Err(e) = stacks.move_crates(i, movement, crane) {
+                return (arrangements, Some(e));
+            }
+            arrangements.push(stacks);
         }
 
-        Ok(())
+        (arrangements, None)
+    }
+
+    /// The crates put down by `movement`, if it was the last move made, as
+    /// their stack index and height above the floor.
+    pub fn moved_crates(&self, movement: &Movement) -> Vec<(usize, usize)> {
+        let Some(index) = self.index_of(movement.destination) else {
+            return Vec::new();
+        };
+        let height = self.stacks[index].0.len();
+        (height.saturating_sub(movement.quantity)..height)
+            .map(|level| (index, level))
+            .collect()
+    }
+
+    /// Draw the stacks as in the puzzle, with every line padded to the same
+    /// width and the stack numbers on the last line. Each crate is passed
+    /// through `paint` with its stack index and height above the floor.
+    pub fn draw(&self, paint: impl Fn(usize, usize, String) -> String) -> String {
+        let height = self.stacks.iter().map(|stack| stack.0.len()).max();
+        let mut lines: Vec<String> = (0..height.unwrap_or(0))
+            .rev()
+            .map(|level| {
+                let row =
+                    self.stacks
+                        .iter()
+                        .enumerate()
+                        .map(|(i, stack)| match stack.0.get(level) {
+                            Some(cargo) => paint(i, level, format!("[{}]", cargo.0)),
+                            None => "   ".to_string(),
+                        });
+                row.collect::<Vec<_>>().join(" ")
+            })
+            .collect();
+
+        let labels = self.ids.iter().map(|id| format!("{:^3}", id));
+        lines.push(labels.collect::<Vec<_>>().join(" "));
+        lines.join("\n")
     }
 }
 
-#[derive(Debug, Default)]
-struct CrateStack(Vec<Crate>);
+impl fmt::Display for Stacks {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.draw(|_, _, text| text))
+    }
+}
 
-impl From<&str> for CrateStack {
-    fn from(s: &str) -> Self {
-        Self(s.chars().map(Crate::from).collect::<Vec<Crate>>())
+/// A model of crane, which decides the order that the crates of a move end
+/// up in.
+pub trait CrateMover {
+    /// Given the crates lifted off the origin stack, bottom first, the order
+    /// they are put down on the destination stack, bottom first.
+    fn unload(&self, lifted: Vec<Crate>) -> Vec<Crate>;
+}
+
+/// Moves crates one at a time, reversing their order.
+pub struct CrateMover9000;
+
+impl CrateMover for CrateMover9000 {
+    fn unload(&self, mut lifted: Vec<Crate>) -> Vec<Crate> {
+        lifted.reverse();
+        lifted
     }
 }
 
-impl CrateStack {
-    /// Move `n` crates from this stack to another.
-    /// Returns an error if `n` exceeds the number of crates in the stack
-    fn _move_crates(&mut self, other: &mut Self, n: usize) -> Result<(), String> {
-        if self.0.len() < n {
-            return Err(format!(
-                "Attempted to move {} crates but CrateStack has {} crates (attempted to
-                move more crates than exist in the stack)",
-                n,
-                self.0.len()
-            ));
-        }
+/// Moves all the crates at once, keeping their order.
+pub struct CrateMover9001;
 
-        // Repeatedly pop/pushing is probably faster than take n/reverse/push n
-        for _ in 0..n {
-            other.0.push(self.0.pop().unwrap())
-        }
+impl CrateMover for CrateMover9001 {
+    fn unload(&self, lifted: Vec<Crate>) -> Vec<Crate> {
+        lifted
+    }
+}
 
-        Ok(())
+/// Moves up to `capacity` crates per trip, taking the top ones first. A
+/// capacity of 1 behaves like the CrateMover 9000, and one at least as large
+/// as every move like the CrateMover 9001.
+pub struct LimitedCrateMover {
+    pub capacity: usize,
+}
+
+impl CrateMover for LimitedCrateMover {
+    fn unload(&self, lifted: Vec<Crate>) -> Vec<Crate> {
+        lifted
+            .rchunks(self.capacity.max(1))
+            .flatten()
+            .copied()
+            .collect()
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+struct CrateStack(Vec<Crate>);
+
+impl From<&str> for CrateStack {
+    fn from(s: &str) -> Self {
+        Self(s.chars().map(Crate::from).collect::<Vec<Crate>>())
     }
 }
 
-#[derive(Debug, Clone, Copy)]
-struct Crate(char);
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Crate(char);
 
 impl From<char> for Crate {
     fn from(c: char) -> Self {
@@ -127,137 +261,301 @@ impl Default for Crate {
     }
 }
 
+/// A step of the rearrangement procedure, between stacks identified by
+/// their labels.
 #[derive(Debug)]
-struct Movement {
+pub struct Movement {
     origin: usize,
     destination: usize,
     quantity: usize,
 }
 
-impl TryFrom<&str> for Movement {
-    type Error = String;
+impl fmt::Display for Movement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "move {} from {} to {}",
+            self.quantity, self.origin, self.destination
+        )
+    }
+}
 
+impl Movement {
     /// Parse a `Movement` from a line formatted as:
     /// 'move n from a to b' where `n` is the quantity of crates to move from
-    /// stack `a` to stack `b`.
+    /// stack `a` to stack `b`, both labels of `stacks`.
     /// Origin and destination cannot be the same.
-    fn try_from(value: &str) -> Result<Self, Self::Error> {
-        let split = value.split(' ');
-
-        match split.collect::<Vec<&str>>()[..] {
-            ["move", quantity, "from", origin, "to", destination] => {
-                let quantity = quantity
-                    .parse()
-                    .map_err(|_| format!("Unable to parse {} as quantity", quantity))?;
-                let origin: usize = origin
-                    .parse()
-                    .map_err(|_| format!("Unable to parse {} as quantity", origin))?;
-                let destination: usize = destination
-                    .parse()
-                    .map_err(|_| format!("Unable to parse {} as quantity", destination))?;
-                if origin == destination {
-                    Err("Origin and destination are the same".to_string())
-                } else {
-                    Ok(Self {
-                        origin: origin - 1,
-                        destination: destination - 1,
-                        quantity,
-                    })
-                }
-            }
-            _ => Err(format!("Unable to parse input {:?}", value)),
+    fn parse(value: &str, stacks: &Stacks) -> Result<Self> {
+        let parse_stack = |field: parse::Capture| {
+            let message = match field.parse::<usize>() {
+                Ok(id) if stacks.index_of(id).is_some() => return Ok(id),
+                Ok(id) => format!("No stack {} in the drawing", id),
+                Err(_) => format!("Unable to parse {} as a stack", field.text),
+            };
+            Err(AocError::parse(1, field.column, message).spanning(field.text))
+        };
+
+        let captures = parse::captures("move {} from {} to {}", value)?;
+        let [quantity, origin, destination] = captures[..] else {
+            unreachable!("The template has three captures")
+        };
+
+        let quantity = quantity.parse()?;
+        let origin_stack = parse_stack(origin)?;
+        let destination_stack = parse_stack(destination)?;
+        if origin_stack == destination_stack {
+            Err(
+                AocError::parse(1, destination.column, "Origin and destination are the same")
+                    .spanning(destination.text),
+            )
+        } else {
+            Ok(Self {
+                origin: origin_stack,
+                destination: destination_stack,
+                quantity,
+            })
         }
     }
 }
 
-pub fn solve_part1() {
-    let input = utils::io::read(5);
-
-    // find the row that separates the stacks and movements and use it to find
-    // the number of stacks. Number of stacks is len/4 + 1 since each number
-    // occupies 3 spaces + a separater between numbers.
-    // This may not work if there are >= 10 stacks.
-    let rows_of_crates = input
-        .lines()
-        .position(|l| l.is_empty())
-        .expect("Could not find empty row to split stacks and movements")
-        - 1;
-    let num_stacks = input
-        .lines()
-        .nth(rows_of_crates)
-        .expect("Could not find row with number of stacks")
-        .len()
-        / 4
-        + 1;
-
-    // Can now create the stacks and iterate in reverse to populate the crates.
-    let mut stacks = Stacks::with_capacity(num_stacks);
-    for row in (0..rows_of_crates).rev() {
-        stacks.parse_row(input.lines().nth(row).unwrap()).unwrap();
-    }
-
-    // number of stacks can be found by inspecting the length of the line of
-    // stack numbers (there are 3 characters per number).
-
-    let moves: Vec<Movement> = input
-        .lines()
-        .skip(10)
-        .map(|l| Movement::try_from(l).unwrap())
-        .collect();
+/// A stack number in the last row of the drawing and the columns it spans.
+struct Label {
+    id: usize,
+    columns: Range<usize>,
+}
 
-    for mov in moves {
-        stacks.move_crates_individually(&mov).unwrap();
+/// The runs of non-whitespace characters in a row, with the column each
+/// starts at.
+fn words(row: &[char]) -> Vec<(usize, String)> {
+    let mut words: Vec<(usize, String)> = Vec::new();
+    let mut in_word = false;
+    for (i, &c) in row.iter().enumerate() {
+        if c.is_whitespace() {
+            in_word = false;
+            continue;
+        }
+        match words.last_mut() {
+            Some((_, word)) if in_word => word.push(c),
+            _ => words.push((i, c.to_string())),
+        }
+        in_word = true;
     }
 
-    let top_crates = stacks
-        .stacks
-        .iter()
-        .map(|stack| stack.0.last().map_or(' ', |c| (*c).into()))
-        .collect::<String>();
+    words
+}
+
+/// Parse the row of stack numbers. Numbers may have any number of digits, so
+/// each stack is found by where its number is rather than assuming the
+/// stacks are evenly spaced.
+fn parse_labels(row: &[char]) -> Result<Vec<Label>> {
+    let mut labels: Vec<Label> = Vec::new();
+    for (start, word) in words(row) {
+        let id = parse::value(&word).map_err(|e| e.offset(0, start))?;
+        if labels.iter().any(|label| label.id == id) {
+            return Err(
+                AocError::parse(1, start + 1, format!("Stack {} is numbered twice", id))
+                    .spanning(&word),
+            );
+        }
+        labels.push(Label {
+            id,
+            columns: start..start + word.chars().count(),
+        });
+    }
 
-    println!("Part 1 :: Crates on top: {}", top_crates);
+    if labels.is_empty() {
+        return Err(AocError::parse(1, 1, "Expected a row of stack numbers"));
+    }
+    Ok(labels)
 }
 
-pub fn solve_part2() {
-    let input = utils::io::read(5);
-
-    let rows_of_crates = input
-        .lines()
-        .position(|l| l.is_empty())
-        .expect("Could not find empty row to split stacks and movements")
-        - 1;
-    let num_stacks = input
-        .lines()
-        .nth(rows_of_crates)
-        .expect("Could not find row with number of stacks")
-        .len()
-        / 4
-        + 1;
-
-    // Can now create the stacks and iterate in reverse to populate the crates.
-    let mut stacks = Stacks::with_capacity(num_stacks);
-    for row in (0..rows_of_crates).rev() {
-        stacks.parse_row(input.lines().nth(row).unwrap()).unwrap();
-    }
-
-    // number of stacks can be found by inspecting the length of the line of
-    // stack numbers (there are 3 characters per number).
-
-    let moves: Vec<Movement> = input
-        .lines()
-        .skip(10)
-        .map(|l| Movement::try_from(l).unwrap())
+/// Parse the drawing of the stacks, collecting every problem found. The
+/// drawing is read from the numbered bottom row up, so any number of crate
+/// rows is supported.
+fn parse_drawing(drawing: &str) -> (Stacks, Vec<AocError>) {
+    // Lines may have had their trailing whitespace trimmed, so pad them all
+    // to the same width.
+    let drawing = Grid::padded(drawing, ' ');
+    let last = drawing.height().saturating_sub(1);
+    let labels = match parse_labels(drawing.rows().last().unwrap_or_default()) {
+        Ok(labels) => labels,
+        Err(e) => return (Stacks::new(Vec::new()), vec![e.offset(last, 0)]),
+    };
+
+    let mut stacks = Stacks::new(labels.iter().map(|label| label.id).collect());
+    let problems = drawing
+        .rows()
+        .take(last)
+        .enumerate()
+        .rev()
+        .enumerate()
+        .filter_map(|(level, (row, line))| {
+            stacks
+                .parse_row(line, &labels, level)
+                .err()
+                .map(|e| e.offset(row, 0))
+        })
         .collect();
 
-    for mov in moves {
-        stacks.move_crates_together(&mov).unwrap();
+    (stacks, problems)
+}
+
+/// The drawing of the stacks, the number of lines before the movements and
+/// the movements, which are separated from the drawing by an empty row.
+fn sections(input: &str) -> Result<(&str, usize, &str)> {
+    match parse::split_groups(input)[..] {
+        [(_, drawing), (moves_line, moves)] => Ok((drawing, moves_line, moves)),
+        _ => Err(AocError::parse(
+            1,
+            1,
+            "Could not find empty row to split stacks and movements",
+        )),
     }
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u32 = 5;
+    const TITLE: &'static str = "Supply Stacks";
 
-    let top_crates = stacks
-        .stacks
-        .iter()
-        .map(|stack| stack.0.last().map_or(' ', |c| (*c).into()))
-        .collect::<String>();
+    /// The starting stacks and the rearrangement procedure.
+    type Input = (Stacks, Vec<Movement>);
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let (drawing, moves_line, moves) = sections(input)?;
+        let (stacks, problems) = parse_drawing(drawing);
+        if let Some(problem) = problems.into_iter().next() {
+            return Err(problem);
+        }
+
+        let moves = parse::lines(moves, |line| Movement::parse(line, &stacks))
+            .map_err(|e| e.offset(moves_line, 0))?;
+
+        Ok((stacks, moves))
+    }
 
-    println!("Part 2 :: Crates on top: {}", top_crates);
+    fn check(input: &str) -> Vec<AocError> {
+        let (drawing, moves_line, moves) = match sections(input) {
+            Ok(sections) => sections,
+            Err(e) => return vec![e],
+        };
+
+        let (stacks, mut problems) = parse_drawing(drawing);
+        if stacks.ids.is_empty() {
+            // Without the stack numbers every move would be reported too.
+            return problems;
+        }
+        let moves = parse::line_errors(moves, |line| Movement::parse(line, &stacks));
+        problems.extend(moves.into_iter().map(|e| e.offset(moves_line, 0)));
+
+        problems
+    }
+
+    fn part1((stacks, moves): &(Stacks, Vec<Movement>)) -> Result<Answer> {
+        let stacks = stacks.rearrange(moves, &CrateMover9000)?;
+        Ok(stacks.top_crates().into())
+    }
+
+    fn part2((stacks, moves): &(Stacks, Vec<Movement>)) -> Result<Answer> {
+        let stacks = stacks.rearrange(moves, &CrateMover9001)?;
+        Ok(stacks.top_crates().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::test_support::parse_position;
+
+    #[test]
+    fn test_parse_many_stacks() {
+        let input = [
+            "[A]                                     [K]",
+            "[B] [C] [D] [E] [F] [G] [H] [I] [J] [L] [M]",
+            " 1   2   3   4   5   6   7   8   9  10  11",
+            "",
+            "move 1 from 11 to 1",
+            "move 2 from 10 to 2",
+        ]
+        .join("\n");
+        let (stacks, moves) = Day5::parse(&input).unwrap();
+        assert_eq!(stacks.ids, (1..=11).collect::<Vec<_>>());
+        assert_eq!(stacks.top_crates(), "ACDEFGHIJLK");
+        assert_eq!(moves[0].origin, 11);
+    }
+
+    #[test]
+    fn test_parse_drawing_errors() {
+        let positions =
+            |input: &str| -> Vec<_> { Day5::check(input).iter().map(parse_position).collect() };
+
+        // A crate beyond the last stack, a floating crate and a move to a
+        // stack that doesn't exist.
+        let input = "    [A]\n[B]     [C]\n 1   2\n\nmove 1 from 1 to 3\n";
+        assert_eq!(positions(input), vec![(2, 9), (1, 5), (5, 18)]);
+        assert_eq!(
+            positions("[A]\n 1  1\n\nmove 1 from 1 to 1\n"),
+            vec![(2, 5)]
+        );
+    }
+
+    #[test]
+    fn test_limited_crate_mover() {
+        let input = "[A]\n[B]\n[C]\n[D]\n[E]\n 1   2\n\nmove 5 from 1 to 2\n";
+        let (stacks, moves) = Day5::parse(input).unwrap();
+        let arrangement = |crane: &dyn CrateMover| {
+            let stacks = stacks.rearrange(&moves, crane).unwrap();
+            stacks.stacks[1]
+                .0
+                .iter()
+                .map(|&c| char::from(c))
+                .collect::<String>()
+        };
+
+        assert_eq!(arrangement(&CrateMover9000), "ABCDE");
+        assert_eq!(arrangement(&CrateMover9001), "EDCBA");
+        assert_eq!(arrangement(&LimitedCrateMover { capacity: 1 }), "ABCDE");
+        assert_eq!(arrangement(&LimitedCrateMover { capacity: 2 }), "BADCE");
+        assert_eq!(arrangement(&LimitedCrateMover { capacity: 5 }), "EDCBA");
+    }
+
+    #[test]
+    fn test_invalid_move_reports_state() {
+        let input = "    [D]\n[N] [C]\n[Z] [M] [P]\n 1   2   3\n\nmove 1 from 2 to 1\nmove 3 from 1 to 3\nmove 3 from 1 to 2\n";
+        let input = Day5::parse(input).unwrap();
+        match Day5::part1(&input) {
+            Err(AocError::InvalidState(message)) => assert_eq!(
+                message,
+                "Move 3 (move 3 from 1 to 2) failed: stack 1 only has 0 crates. \
+                 Stack heights were 1: 0, 2: 2, 3: 4"
+            ),
+            other => panic!("Expected the third move to fail, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_display_round_trip() {
+        let drawing = "    [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 ";
+        let (stacks, moves) = Day5::parse(&format!("{}\n\nmove 3 from 2 to 1\n", drawing)).unwrap();
+        assert_eq!(stacks.to_string(), drawing);
+
+        let stacks = stacks.rearrange(&moves, &CrateMover9001).unwrap();
+        assert_eq!(
+            stacks.to_string(),
+            "[D]        \n[C]        \n[M]        \n[N]        \n[Z]     [P]\n 1   2   3 "
+        );
+
+        let many = Stacks {
+            ids: (1..=12).collect(),
+            stacks: (0..12)
+                .map(|i| CrateStack::from(&"ABCDEFGHIJKL"[..i]))
+                .collect(),
+        };
+        for stacks in [stacks, many] {
+            let (parsed, problems) = parse_drawing(&stacks.to_string());
+            assert!(problems.is_empty(), "{:?}", problems);
+            assert_eq!(parsed, stacks);
+        }
+    }
 }
@@ -1,9 +1,13 @@
 use std::collections::HashSet;
 
-use crate::utils;
+use crate::{
+    error::{AocError, Result},
+    solutions::{Answer, Solution},
+};
 
-/// Find the index after the first stream of `n` unique characters in the input
-fn position_after_consecutive_unique_n(input: &str, n: usize) -> usize {
+/// Find the index after the first stream of `n` unique characters in the
+/// input, if there is one.
+fn position_after_consecutive_unique_n(input: &str, n: usize) -> Option<usize> {
     input
         .char_indices()
         .flat_map(move |(from, _)| {
@@ -13,25 +17,62 @@ fn position_after_consecutive_unique_n(input: &str, n: usize) -> usize {
                 .map(|(to, c)| &input[from..from + to + c.len_utf8()])
         })
         .position(|chars| chars.chars().collect::<HashSet<char>>().len() == n)
-        .unwrap()
-        + n // iterator starts at position 3, so need to add 4 to find end of
-            // packet.
+        // iterator starts at position 3, so need to add 4 to find end of packet.
+        .map(|position| position + n)
 }
 
-pub fn solve_part1() {
-    let input = utils::io::read(6);
+pub struct Day6;
 
-    let score = position_after_consecutive_unique_n(&input, 4);
+impl Solution for Day6 {
+    const DAY: u32 = 6;
+    const TITLE: &'static str = "Tuning Trouble";
 
-    println!("Part 1 :: Packet starts at: {}", score);
-}
+    /// The datastream buffer.
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.trim().to_string())
+    }
+
+    fn check(input: &str) -> Vec<AocError> {
+        let mut problems: Vec<AocError> = input
+            .lines()
+            .enumerate()
+            .flat_map(|(i, line)| {
+                line.chars()
+                    .enumerate()
+                    .filter(|(_, c)| !c.is_ascii_lowercase())
+                    .map(move |(j, c)| {
+                        AocError::parse(i + 1, j + 1, format!("Unexpected character {:?}", c))
+                    })
+            })
+            .collect();
+        match input.lines().count() {
+            0 => problems.push(AocError::InvalidState(
+                "The datastream is empty".to_string(),
+            )),
+            1 => {}
+            _ => problems.push(AocError::parse(2, 1, "Expected a single line")),
+        }
+
+        problems
+    }
+
+    fn part1(input: &String) -> Result<Answer> {
+        let score = position_after_consecutive_unique_n(input, 4).ok_or_else(|| {
+            AocError::NoSolution("No start-of-packet marker in datastream".to_string())
+        })?;
 
-pub fn solve_part2() {
-    let input = utils::io::read(6);
+        score.try_into()
+    }
 
-    let score = position_after_consecutive_unique_n(&input, 14);
+    fn part2(input: &String) -> Result<Answer> {
+        let score = position_after_consecutive_unique_n(input, 14).ok_or_else(|| {
+            AocError::NoSolution("No start-of-message marker in datastream".to_string())
+        })?;
 
-    println!("Part 2 :: Message starts at: {}", score);
+        score.try_into()
+    }
 }
 
 #[cfg(test)]
@@ -49,7 +90,10 @@ mod tests {
         ];
 
         for (data, start_location) in inputs {
-            assert_eq!(position_after_consecutive_unique_n(data, 4), start_location)
+            assert_eq!(
+                position_after_consecutive_unique_n(data, 4),
+                Some(start_location)
+            )
         }
     }
 
@@ -66,7 +110,7 @@ mod tests {
         for (data, start_location) in inputs {
             assert_eq!(
                 position_after_consecutive_unique_n(data, 14),
-                start_location
+                Some(start_location)
             )
         }
     }
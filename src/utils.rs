@@ -1 +1,8 @@
+pub mod answers;
+pub mod client;
+pub mod examples;
+pub mod guesses;
 pub mod io;
+pub mod parse;
+pub mod timing;
+pub mod toml_file;
@@ -0,0 +1,83 @@
+//! Known answers for the real puzzle inputs, used to catch regressions.
+//!
+//! Answers are stored in `answers.toml`, keyed by day and part:
+//!
+//! ```toml
+//! [day5]
+//! part1 = "CNSZFDVLJ"
+//! part2 = "QNDWLMGNS"
+//! ```
+
+use std::{collections::BTreeMap, path::PathBuf};
+
+use serde::Deserialize;
+
+use crate::{
+    config::Config,
+    error::Result,
+    solutions::{Answer, Part},
+    utils::toml_file,
+};
+
+/// Answers file looked for in the working directory.
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DayAnswers {
+    pub part1: Option<Answer>,
+    pub part2: Option<Answer>,
+}
+
+impl DayAnswers {
+    pub fn part(&self, part: Part) -> Option<&Answer> {
+        match part {
+            Part::One => self.part1.as_ref(),
+            Part::Two => self.part2.as_ref(),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize)]
+#[serde(transparent)]
+pub struct Answers(BTreeMap<String, DayAnswers>);
+
+impl Answers {
+    /// The answers file to use, see [`toml_file::locate`].
+    pub fn path(config: &Config) -> PathBuf {
+        toml_file::locate(config.answers.as_deref(), ANSWERS_FILE)
+    }
+
+    /// Load the answers file, treating a missing file as having no answers.
+    pub fn load(config: &Config) -> Result<Self> {
+        let path = Self::path(config);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        toml_file::load(&path, "answers")
+    }
+
+    pub fn parse(text: &str) -> Result<Self> {
+        toml_file::parse(text, "answers")
+    }
+
+    /// The known answer for `part` of `day`, if one has been recorded.
+    pub fn get(&self, day: u32, part: Part) -> Option<&Answer> {
+        self.0.get(&format!("day{}", day))?.part(part)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_answers() {
+        let answers = Answers::parse("[day5]\npart1 = \"CMZ\"\n\n[day6]\npart2 = 19\n").unwrap();
+
+        assert_eq!(answers.get(5, Part::One), Some(&Answer::from("CMZ")));
+        assert_eq!(answers.get(5, Part::Two), None);
+        assert_eq!(answers.get(6, Part::Two), Some(&Answer::Int(19)));
+        assert_eq!(answers.get(1, Part::One), None);
+    }
+}
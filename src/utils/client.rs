@@ -0,0 +1,305 @@
+//! A minimal client for the puzzle website.
+//!
+//! Requests are authenticated with the session cookie of a logged-in browser,
+//! read from `AOC_SESSION` or the config file's `session`. The base URL can be
+//! changed with the config file's `base_url`, for example to test against a
+//! local server.
+
+use std::{env, time::Duration};
+
+use crate::{
+    config::Config,
+    error::{AocError, Result},
+    solutions::{Answer, Part},
+};
+
+/// Environment variable holding the session cookie.
+pub const SESSION_ENV: &str = "AOC_SESSION";
+/// Where the 2022 puzzles are served from.
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com/2022";
+
+const USER_AGENT: &str = "github.com/Jarrod-Bennett/aoc22";
+
+/// The website's response to a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint of which way.
+    Wrong,
+    /// An answer was submitted too recently; try again after this long.
+    Wait(Duration),
+    /// The part was already solved, or is not unlocked yet.
+    AlreadySolved,
+    /// A response that isn't recognised, as plain text.
+    Unknown(String),
+}
+
+/// Parse a wait such as `1m 5s` into a duration.
+fn parse_wait(text: &str) -> Option<Duration> {
+    text.split_whitespace()
+        .try_fold(Duration::ZERO, |total, part| {
+            let (n, unit) = part.split_at(part.find(|c: char| !c.is_ascii_digit())?);
+            let n: u64 = n.parse().ok()?;
+            let seconds = match unit {
+                "h" => n * 3600,
+                "m" => n * 60,
+                "s" => n,
+                _ => return None,
+            };
+            Some(total + Duration::from_secs(seconds))
+        })
+}
+
+/// The text of an HTML page's `<article>`, or of the whole page, without
+/// tags and with whitespace collapsed.
+fn page_text(html: &str) -> String {
+    let article = html
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once('>'))
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or(html, |(article, _)| article);
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+impl Verdict {
+    /// Interpret the page returned after submitting an answer.
+    pub fn parse(html: &str) -> Self {
+        let text = page_text(html);
+        if text.contains("That's the right answer") {
+            Verdict::Correct
+        } else if text.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if text.contains("your answer is too low") {
+            Verdict::TooLow
+        } else if text.contains("That's not the right answer") {
+            Verdict::Wrong
+        } else if text.contains("You don't seem to be solving the right level") {
+            Verdict::AlreadySolved
+        } else if let Some(wait) = text
+            .split_once("You have ")
+            .and_then(|(_, rest)| rest.split_once(" left to wait"))
+            .and_then(|(wait, _)| parse_wait(wait))
+        {
+            Verdict::Wait(wait)
+        } else {
+            Verdict::Unknown(text)
+        }
+    }
+}
+
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+}
+
+impl Client {
+    pub fn new(base_url: impl Into<String>, session: impl Into<String>) -> Self {
+        Self {
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(Duration::from_secs(30))
+                .build(),
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            session: session.into(),
+        }
+    }
+
+    /// Create a client from `AOC_SESSION` (or the config's `session`) and the
+    /// config's `base_url`.
+    pub fn from_config(config: &Config) -> Result<Self> {
+        let session = env::var(SESSION_ENV)
+            .ok()
+            .or_else(|| config.session.clone())
+            .filter(|s| !s.trim().is_empty())
+            .ok_or_else(|| {
+                AocError::Config(format!(
+                    "No session token, set {} or `session` in the config file",
+                    SESSION_ENV
+                ))
+            })?;
+        let base_url = config.base_url.as_deref().unwrap_or(DEFAULT_BASE_URL);
+
+        Ok(Self::new(base_url, session.trim()))
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}{}", self.base_url, path)
+    }
+
+    fn send(&self, url: &str, request: ureq::Request, form: &[(&str, &str)]) -> Result<String> {
+        let request = request.set("Cookie", &format!("session={}", self.session));
+        let response = if form.is_empty() {
+            request.call()
+        } else {
+            request.send_form(form)
+        };
+        let error = |message: String| AocError::Http {
+            url: url.to_string(),
+            message,
+        };
+
+        match response {
+            Ok(response) => response.into_string().map_err(|e| error(e.to_string())),
+            Err(ureq::Error::Status(code, response)) => {
+                let body = response.into_string().unwrap_or_default();
+                Err(error(format!("{} {}", code, body.trim())))
+            }
+            Err(e) => Err(error(e.to_string())),
+        }
+    }
+
+    /// Download the puzzle input for `day`.
+    pub fn input(&self, day: u32) -> Result<String> {
+        let url = self.url(&format!("/day/{}/input", day));
+        self.send(&url, self.agent.get(&url), &[])
+    }
+
+    /// Submit `answer` for `part` of `day`.
+    pub fn submit(&self, day: u32, part: Part, answer: &Answer) -> Result<Verdict> {
+        let url = self.url(&format!("/day/{}/answer", day));
+        let level = part.to_string();
+        let answer = answer.to_string();
+        let page = self.send(
+            &url,
+            self.agent.post(&url),
+            &[("level", &level), ("answer", &answer)],
+        )?;
+
+        Ok(Verdict::parse(&page))
+    }
+}
+
+/// A local HTTP server for testing the client.
+#[cfg(test)]
+pub(crate) mod stub {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        thread::{self, JoinHandle},
+    };
+
+    /// Serve one connection per response, in order, from an unused local
+    /// port. Returns the server's URL and a handle resolving to the raw
+    /// requests received.
+    pub fn serve(responses: Vec<(u16, &'static str)>) -> (String, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let mut requests = Vec::new();
+            for (status, body) in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+
+                let mut request = String::new();
+                let mut length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some((name, value)) = line.split_once(':') {
+                        if name.eq_ignore_ascii_case("content-length") {
+                            length = value.trim().parse().unwrap();
+                        }
+                    }
+                    request.push_str(&line);
+                    if line == "\r\n" || line.is_empty() {
+                        break;
+                    }
+                }
+                let mut body_in = vec![0; length];
+                reader.read_exact(&mut body_in).unwrap();
+                request.push_str(&String::from_utf8(body_in).unwrap());
+                requests.push(request);
+
+                write!(
+                    reader.get_mut(),
+                    "HTTP/1.1 {} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+            }
+            requests
+        });
+
+        (url, handle)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_request() {
+        let (url, server) = stub::serve(vec![(200, "1\n2\n"), (404, "Not found")]);
+        let client = Client::new(format!("{}/", url), "abc123");
+
+        assert_eq!(client.input(3).unwrap(), "1\n2\n");
+        match client.input(4) {
+            Err(AocError::Http { message, .. }) => assert_eq!(message, "404 Not found"),
+            other => panic!("Expected an HTTP error, got {:?}", other),
+        }
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("GET /day/3/input HTTP/1.1\r\n"));
+        assert!(requests[0].contains("Cookie: session=abc123\r\n"));
+    }
+
+    #[test]
+    fn test_parse_verdict() {
+        let page = |text: &str| format!("<main>\n<article><p>{}</p></article>\n</main>", text);
+
+        assert_eq!(
+            Verdict::parse(&page(
+                "That's the right answer!  You are <em>one gold star</em> closer."
+            )),
+            Verdict::Correct
+        );
+        assert_eq!(
+            Verdict::parse(&page(
+                "That's not the right answer; your answer is too high."
+            )),
+            Verdict::TooHigh
+        );
+        assert_eq!(
+            Verdict::parse(&page(
+                "That's not the right answer; your answer is too low."
+            )),
+            Verdict::TooLow
+        );
+        assert_eq!(
+            Verdict::parse(&page("That's not the right answer. If you're stuck, ...")),
+            Verdict::Wrong
+        );
+        assert_eq!(
+            Verdict::parse(&page(
+                "You gave an answer too recently. You have 1m 5s left to wait."
+            )),
+            Verdict::Wait(Duration::from_secs(65))
+        );
+        assert_eq!(
+            Verdict::parse(&page("You don't seem to be solving the right level.")),
+            Verdict::AlreadySolved
+        );
+        assert_eq!(
+            Verdict::parse("<p>Something\n else</p>"),
+            Verdict::Unknown("Something else".to_string())
+        );
+    }
+}
@@ -0,0 +1,64 @@
+//! Puzzle examples and their expected answers.
+//!
+//! Each day's examples live in `examples/day{N}.toml` within an input
+//! directory, as a list of `[[example]]` tables:
+//!
+//! ```toml
+//! [[example]]
+//! input = '''
+//! A Y
+//! B X
+//! '''
+//! part1 = 15
+//! part2 = "CMZ"
+//! ```
+//!
+//! Either answer may be omitted when the puzzle only gives one.
+
+use std::path::PathBuf;
+
+use serde::Deserialize;
+
+use crate::{
+    config::Config,
+    error::{AocError, Result},
+    solutions::Answer,
+    utils::{io, toml_file},
+};
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Example {
+    pub input: String,
+    pub part1: Option<Answer>,
+    pub part2: Option<Answer>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ExampleFile {
+    example: Vec<Example>,
+}
+
+/// Candidate paths of the examples file for `day`, one per input directory.
+pub fn paths(day: u32, config: &Config) -> Vec<PathBuf> {
+    io::input_dirs(config)
+        .into_iter()
+        .map(|dir| dir.join("examples").join(format!("day{}.toml", day)))
+        .collect()
+}
+
+/// Load the examples for `day` from the first examples file found.
+pub fn load(day: u32, config: &Config) -> Result<Vec<Example>> {
+    let tried = paths(day, config);
+    let path = tried
+        .iter()
+        .find(|path| path.is_file())
+        .ok_or_else(|| AocError::InputNotFound {
+            day,
+            tried: tried.clone(),
+        })?;
+
+    let file: ExampleFile = toml_file::load(path, "examples")?;
+    Ok(file.example)
+}
@@ -0,0 +1,161 @@
+//! A record of the answers submitted for each part, so that a guess already
+//! known to be wrong is never submitted again.
+//!
+//! Guesses are stored in `guesses.toml`, keyed by day and part:
+//!
+//! ```toml
+//! [day1.part1]
+//! too_low = 70000
+//! too_high = 80000
+//! wrong = [70000, 80000, 75000]
+//! ```
+//!
+//! `too_low` is the highest guess that was too low and `too_high` the lowest
+//! that was too high. Once a part is solved its answer is stored as `correct`.
+
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    config::Config,
+    error::{AocError, Result},
+    solutions::{Answer, Part},
+    utils::{client::Verdict, toml_file},
+};
+
+/// Guesses file looked for in the working directory.
+pub const GUESSES_FILE: &str = "guesses.toml";
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PartGuesses {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub correct: Option<Answer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub too_low: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub too_high: Option<i64>,
+    pub wrong: Vec<Answer>,
+}
+
+impl PartGuesses {
+    /// Why `answer` cannot be correct, if the past guesses rule it out.
+    pub fn rule_out(&self, answer: &Answer) -> Option<String> {
+        if let Some(correct) = &self.correct {
+            return Some(if correct == answer {
+                format!("{} was already accepted as correct", answer)
+            } else {
+                format!("the correct answer is {}", correct)
+            });
+        }
+        if self.wrong.contains(answer) {
+            return Some(format!("{} was already submitted and is wrong", answer));
+        }
+        if let Answer::Int(n) = *answer {
+            match (self.too_low, self.too_high) {
+                (Some(low), _) if n <= low => {
+                    return Some(format!("{} is too low, {} was already too low", n, low))
+                }
+                (_, Some(high)) if n >= high => {
+                    return Some(format!("{} is too high, {} was already too high", n, high))
+                }
+                _ => {}
+            }
+        }
+        None
+    }
+
+    /// Record the verdict on a submitted `answer`.
+    pub fn record(&mut self, answer: &Answer, verdict: &Verdict) {
+        let n = match answer {
+            Answer::Int(n) => Some(*n),
+            Answer::Text(_) => None,
+        };
+        match verdict {
+            Verdict::Correct => {
+                self.correct = Some(answer.clone());
+                return;
+            }
+            Verdict::TooLow => self.too_low = self.too_low.max(n),
+            Verdict::TooHigh => self.too_high = self.too_high.into_iter().chain(n).min(),
+            Verdict::Wrong => {}
+            Verdict::Wait(_) | Verdict::AlreadySolved | Verdict::Unknown(_) => return,
+        }
+        if !self.wrong.contains(answer) {
+            self.wrong.push(answer.clone());
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Guesses(BTreeMap<String, BTreeMap<String, PartGuesses>>);
+
+impl Guesses {
+    /// The guesses file to use, see [`toml_file::locate`].
+    pub fn path(config: &Config) -> PathBuf {
+        toml_file::locate(config.guesses.as_deref(), GUESSES_FILE)
+    }
+
+    /// Load the guesses file at `path`, treating a missing file as having no
+    /// guesses.
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        toml_file::load(path, "guesses")
+    }
+
+    pub fn parse(text: &str) -> Result<Self> {
+        toml_file::parse(text, "guesses")
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let text = toml::to_string(self).expect("Guesses are always serializable");
+        fs::write(path, text).map_err(|source| AocError::Io {
+            path: path.to_path_buf(),
+            source,
+        })
+    }
+
+    /// The guesses for `part` of `day`, created if there are none yet.
+    pub fn part_mut(&mut self, day: u32, part: Part) -> &mut PartGuesses {
+        self.0
+            .entry(format!("day{}", day))
+            .or_default()
+            .entry(format!("part{}", part))
+            .or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_guesses_rule_out_answers() {
+        let mut guesses = Guesses::default();
+        let part = guesses.part_mut(1, Part::One);
+        part.record(&100.into(), &Verdict::TooHigh);
+        part.record(&10.into(), &Verdict::TooLow);
+        part.record(&50.into(), &Verdict::Wrong);
+
+        assert!(part.rule_out(&100.into()).is_some());
+        assert!(part.rule_out(&120.into()).is_some());
+        assert!(part.rule_out(&10.into()).is_some());
+        assert!(part.rule_out(&50.into()).is_some());
+        assert!(part.rule_out(&60.into()).is_none());
+
+        part.record(&60.into(), &Verdict::Correct);
+        assert!(part.rule_out(&70.into()).is_some());
+
+        let text = toml::to_string(&guesses).unwrap();
+        assert_eq!(Guesses::parse(&text).unwrap(), guesses);
+        assert!(text.contains("[day1.part1]"));
+    }
+}
@@ -1,8 +1,186 @@
 //! Handle reading input files in various forms
+//!
+//! With the `embed-inputs` feature, the inputs in `src/inputs` are compiled
+//! into the binary and served from memory, so it can run from anywhere.
+//! Days without an embedded input are still searched for on disk.
 
-use std::fs;
+use std::{
+    env, fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
 
-pub fn read(day: u32) -> String {
-    let filename = format!("src/inputs/day{}.txt", day);
-    fs::read_to_string(filename).expect("Unable to read file.")
+use crate::{
+    config::Config,
+    error::{AocError, Result},
+};
+
+/// Environment variable naming a directory containing `day{N}.txt` inputs.
+pub const INPUT_DIR_ENV: &str = "AOC_INPUT_DIR";
+
+/// A puzzle input and the file it was read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input {
+    pub text: String,
+    /// The file read, `<stdin>` or `<embedded>` for an input compiled in.
+    pub path: PathBuf,
+}
+
+/// Where to read a day's input from.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum Source {
+    /// Search the input directories given by [`input_dirs`].
+    #[default]
+    Search,
+    /// Read an explicit file.
+    File(PathBuf),
+    /// Read standard input.
+    Stdin,
+}
+
+impl From<&str> for Source {
+    /// `-` selects standard input, anything else is a file path.
+    fn from(arg: &str) -> Self {
+        match arg {
+            "-" => Source::Stdin,
+            path => Source::File(path.into()),
+        }
+    }
+}
+
+/// The directories searched for inputs, in order: `AOC_INPUT_DIR`, the
+/// config file's `input_dir`, `src/inputs` under the working directory and
+/// finally `src/inputs` in the crate the binary was built from.
+pub fn input_dirs(config: &Config) -> Vec<PathBuf> {
+    let mut dirs = Vec::new();
+    if let Some(dir) = env::var_os(INPUT_DIR_ENV) {
+        dirs.push(PathBuf::from(dir));
+    }
+    dirs.extend(config.input_dir.clone());
+    dirs.push(PathBuf::from("src/inputs"));
+    dirs.push(Path::new(env!("CARGO_MANIFEST_DIR")).join("src/inputs"));
+    dirs.dedup();
+
+    dirs
+}
+
+/// The directory new inputs are saved to: `AOC_INPUT_DIR` or the config
+/// file's `input_dir` if either is set, otherwise the first of the default
+/// directories that exists.
+pub fn save_dir(config: &Config) -> PathBuf {
+    if let Some(dir) = env::var_os(INPUT_DIR_ENV) {
+        return PathBuf::from(dir);
+    }
+    if let Some(dir) = &config.input_dir {
+        return dir.clone();
+    }
+    let local = PathBuf::from("src/inputs");
+    if local.is_dir() {
+        local
+    } else {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("src/inputs")
+    }
+}
+
+/// Name of the input file for `day` within an input directory.
+pub fn file_name(day: u32) -> String {
+    format!("day{}.txt", day)
+}
+
+#[cfg(feature = "embed-inputs")]
+mod embedded {
+    include!(concat!(env!("OUT_DIR"), "/inputs.rs"));
+}
+
+/// The input for `day` compiled into the binary.
+#[cfg(feature = "embed-inputs")]
+pub fn embedded(day: u32) -> Option<&'static str> {
+    embedded::INPUTS
+        .iter()
+        .find(|(d, _)| *d == day)
+        .map(|(_, input)| *input)
+}
+
+/// Inputs are only compiled in with the `embed-inputs` feature.
+#[cfg(not(feature = "embed-inputs"))]
+pub fn embedded(_day: u32) -> Option<&'static str> {
+    None
+}
+
+fn read_file(path: &Path) -> Result<Input> {
+    let text = fs::read_to_string(path).map_err(|source| AocError::Io {
+        path: path.to_path_buf(),
+        source,
+    })?;
+
+    Ok(Input {
+        text,
+        path: path.to_path_buf(),
+    })
+}
+
+pub fn read(day: u32, source: &Source, config: &Config) -> Result<Input> {
+    match source {
+        Source::File(path) => read_file(path),
+        Source::Stdin => {
+            let mut text = String::new();
+            io::stdin()
+                .read_to_string(&mut text)
+                .map_err(|source| AocError::Io {
+                    path: "<stdin>".into(),
+                    source,
+                })?;
+            Ok(Input {
+                text,
+                path: "<stdin>".into(),
+            })
+        }
+        Source::Search => {
+            if let Some(text) = embedded(day) {
+                return Ok(Input {
+                    text: text.to_string(),
+                    path: "<embedded>".into(),
+                });
+            }
+            let tried: Vec<PathBuf> = input_dirs(config)
+                .into_iter()
+                .map(|dir| dir.join(file_name(day)))
+                .collect();
+            match tried.iter().find(|path| path.is_file()) {
+                Some(path) => read_file(path),
+                None => Err(AocError::InputNotFound { day, tried }),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "embed-inputs")]
+    #[test]
+    fn test_embedded_inputs() {
+        let on_disk = Path::new(env!("CARGO_MANIFEST_DIR")).join("src/inputs/day1.txt");
+        assert_eq!(
+            embedded(1).map(String::from),
+            fs::read_to_string(on_disk).ok()
+        );
+        assert!(embedded(99).is_none());
+    }
+
+    #[test]
+    fn test_missing_input_lists_tried_paths() {
+        let config = Config {
+            input_dir: Some(PathBuf::from("does/not/exist")),
+            ..Config::default()
+        };
+        match read(99, &Source::Search, &config) {
+            Err(AocError::InputNotFound { day, tried }) => {
+                assert_eq!(day, 99);
+                assert!(tried.contains(&PathBuf::from("does/not/exist/day99.txt")));
+            }
+            other => panic!("Expected InputNotFound, got {:?}", other),
+        }
+    }
 }
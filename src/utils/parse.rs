@@ -0,0 +1,410 @@
+//! Helpers for the parsing every day repeats: numbered lines, groups of lines
+//! separated by blank lines, integers, fixed-format lines and character
+//! grids.
+//!
+//! Helpers that parse a single line report errors on line 1, and [`lines`]
+//! and [`groups`] shift those errors to the line they came from, so errors
+//! always point into the whole input.
+
+use std::{any, str::FromStr};
+
+use crate::error::{AocError, Result};
+
+/// 1-based column of `part`, a slice of `text`.
+fn column(text: &str, part: &str) -> usize {
+    let offset = part.as_ptr() as usize - text.as_ptr() as usize;
+    text[..offset].chars().count() + 1
+}
+
+/// The name of `T` without its module path.
+fn type_name<T>() -> &'static str {
+    let name = any::type_name::<T>();
+    name.rsplit("::").next().unwrap_or(name)
+}
+
+/// Parse `text` as a `T`, reporting an error at column 1.
+pub fn value<T: FromStr>(text: &str) -> Result<T> {
+    text.parse().map_err(|_| {
+        AocError::parse(
+            1,
+            1,
+            format!("Could not parse {:?} as {}", text, type_name::<T>()),
+        )
+        .spanning(text)
+    })
+}
+
+/// Parse each line of `input` with `f`.
+pub fn lines<T>(input: &str, mut f: impl FnMut(&str) -> Result<T>) -> Result<Vec<T>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| f(line).map_err(|e| e.offset(i, 0)))
+        .collect()
+}
+
+/// Parse every line of `input` with `f`, returning all of the errors rather
+/// than stopping at the first.
+pub fn line_errors<T>(input: &str, mut f: impl FnMut(&str) -> Result<T>) -> Vec<AocError> {
+    line_problems(input, |line| f(line).err().into_iter().collect())
+}
+
+/// Every problem that `f` finds on each line of `input`, for lines that can
+/// have more than one.
+pub fn line_problems(input: &str, mut f: impl FnMut(&str) -> Vec<AocError>) -> Vec<AocError> {
+    input
+        .lines()
+        .enumerate()
+        .flat_map(|(i, line)| f(line).into_iter().map(move |e| e.offset(i, 0)))
+        .collect()
+}
+
+/// Split `input` into groups of lines separated by one or more blank lines.
+/// Each group is given with the number of lines before it in `input`, and
+/// without its final newline.
+pub fn split_groups(input: &str) -> Vec<(usize, &str)> {
+    let mut groups = Vec::new();
+    // The first line and byte offset of the current group, and where it ends.
+    let mut group: Option<(usize, usize)> = None;
+    let mut end = 0;
+    let mut offset = 0;
+
+    for (i, line) in input.split_inclusive('\n').enumerate() {
+        let content = line.trim_end_matches(['\n', '\r']);
+        if content.trim().is_empty() {
+            if let Some((first, start)) = group.take() {
+                groups.push((first, &input[start..end]));
+            }
+        } else {
+            group.get_or_insert((i, offset));
+            end = offset + content.len();
+        }
+        offset += line.len();
+    }
+    if let Some((first, start)) = group {
+        groups.push((first, &input[start..end]));
+    }
+
+    groups
+}
+
+/// Parse each blank-line separated group of `input` with `f`.
+pub fn groups<T>(input: &str, mut f: impl FnMut(&str) -> Result<T>) -> Result<Vec<T>> {
+    split_groups(input)
+        .into_iter()
+        .map(|(line, group)| f(group).map_err(|e| e.offset(line, 0)))
+        .collect()
+}
+
+/// Every integer in `text`. A `-` makes an integer negative unless it follows
+/// a letter or digit, so `x=-2` is -2 but `2-4` is 2 and 4.
+pub fn ints(text: &str) -> Result<Vec<i64>> {
+    let mut ints = Vec::new();
+    let mut from = 0;
+
+    while let Some(found) = text[from..].find(|c: char| c.is_ascii_digit()) {
+        let digits = from + found;
+        let before = text[..digits].chars().rev();
+        let start = match before.take(2).collect::<Vec<char>>()[..] {
+            ['-', c, ..] if !c.is_alphanumeric() => digits - 1,
+            ['-'] => digits - 1,
+            _ => digits,
+        };
+        let end = text[digits..]
+            .find(|c: char| !c.is_ascii_digit())
+            .map_or(text.len(), |len| digits + len);
+        let number = &text[start..end];
+
+        ints.push(value(number).map_err(|e| e.offset(0, column(text, number) - 1))?);
+        from = end;
+    }
+
+    Ok(ints)
+}
+
+/// A value captured by a `{}` in a template, and its 1-based column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Capture<'a> {
+    pub text: &'a str,
+    pub column: usize,
+}
+
+impl Capture<'_> {
+    pub fn parse<T: FromStr>(&self) -> Result<T> {
+        value(self.text).map_err(|e| e.offset(0, self.column - 1))
+    }
+}
+
+/// Match `line` against `template`, in which each `{}` captures the
+/// non-empty text between the literal parts around it.
+///
+/// # Panics
+///
+/// If `template` has two adjacent captures, which would be ambiguous.
+pub fn captures<'a>(template: &str, line: &'a str) -> Result<Vec<Capture<'a>>> {
+    let mut literals = template.split("{}");
+    let first = literals.next().unwrap_or_default();
+    let literals: Vec<&str> = literals.collect();
+
+    let expected = |at: &str, what: String| {
+        Err(AocError::parse(
+            1,
+            column(line, at),
+            format!("Expected {} in {:?}", what, line),
+        ))
+    };
+    let Some(mut rest) = line.strip_prefix(first) else {
+        return expected(line, format!("{:?}", first));
+    };
+
+    let mut captures = Vec::with_capacity(literals.len());
+    for (i, literal) in literals.iter().enumerate() {
+        let last = i + 1 == literals.len();
+        assert!(
+            last || !literal.is_empty(),
+            "Template {:?} has adjacent captures",
+            template
+        );
+
+        let end = if last {
+            match rest.strip_suffix(literal) {
+                Some(text) => text.len(),
+                None => return expected(&rest[rest.len()..], format!("{:?} at the end", literal)),
+            }
+        } else {
+            match rest.find(literal) {
+                Some(end) => end,
+                None => return expected(&rest[rest.len()..], format!("{:?}", literal)),
+            }
+        };
+        if end == 0 {
+            return expected(rest, "a value".to_string());
+        }
+
+        captures.push(Capture {
+            text: &rest[..end],
+            column: column(line, rest),
+        });
+        rest = &rest[end + literal.len()..];
+    }
+
+    Ok(captures)
+}
+
+/// A tuple that can be parsed from the captures of a template.
+pub trait FromCaptures: Sized {
+    const COUNT: usize;
+
+    fn from_captures(captures: &[Capture<'_>]) -> Result<Self>;
+}
+
+macro_rules! impl_from_captures {
+    ($($t:ident),+) => {
+        impl<$($t: FromStr),+> FromCaptures for ($($t,)+) {
+            const COUNT: usize = [$(stringify!($t)),+].len();
+
+            fn from_captures(captures: &[Capture<'_>]) -> Result<Self> {
+                let mut captures = captures.iter();
+                Ok(($(
+                    captures
+                        .next()
+                        .expect("Template has a capture for each value")
+                        .parse::<$t>()?,
+                )+))
+            }
+        }
+    };
+}
+
+impl_from_captures!(A);
+impl_from_captures!(A, B);
+impl_from_captures!(A, B, C);
+impl_from_captures!(A, B, C, D);
+impl_from_captures!(A, B, C, D, E);
+impl_from_captures!(A, B, C, D, E, F);
+
+/// Match `line` against `template` and parse each capture into the matching
+/// element of `T`:
+///
+/// ```
+/// # use aoc22::utils::parse::template;
+/// let line = "move 1 from 2 to 3";
+/// let (n, from, to): (usize, usize, usize) = template("move {} from {} to {}", line)?;
+/// assert_eq!((n, from, to), (1, 2, 3));
+/// # Ok::<(), aoc22::AocError>(())
+/// ```
+///
+/// # Panics
+///
+/// If the number of captures in `template` differs from the size of `T`.
+pub fn template<T: FromCaptures>(template: &str, line: &str) -> Result<T> {
+    let captures = captures(template, line)?;
+    assert_eq!(
+        captures.len(),
+        T::COUNT,
+        "Template {:?} does not match the number of values",
+        template
+    );
+
+    T::from_captures(&captures)
+}
+
+/// A rectangular grid of cells, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Parse a grid with one row per line, converting each character with
+    /// `f`. Every line must be the same width.
+    pub fn parse_with(input: &str, mut f: impl FnMut(char) -> Option<T>) -> Result<Self> {
+        let mut width = None;
+        let mut cells = Vec::new();
+        let mut height = 0;
+
+        for (i, line) in input.lines().enumerate() {
+            let row_width = line.chars().count();
+            match width {
+                Some(width) if width != row_width => {
+                    return Err(AocError::parse(
+                        i + 1,
+                        row_width.min(width) + 1,
+                        format!("Expected a row of {} cells, found {}", width, row_width),
+                    ))
+                }
+                _ => width = Some(row_width),
+            }
+            for (j, c) in line.chars().enumerate() {
+                let cell = f(c).ok_or_else(|| {
+                    AocError::parse(i + 1, j + 1, format!("Unexpected character {:?}", c))
+                })?;
+                cells.push(cell);
+            }
+            height += 1;
+        }
+
+        Ok(Self {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The cell in column `x` of row `y`, counting from the top left.
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        if x < self.width && y < self.height {
+            self.cells.get(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    pub fn rows(&self) -> impl ExactSizeIterator<Item = &[T]> + DoubleEndedIterator {
+        // `chunks` panics on a size of zero, which an empty grid would give.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// The cells of column `x`, from top to bottom.
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> {
+        self.rows().filter_map(move |row| row.get(x))
+    }
+}
+
+impl Grid<char> {
+    /// Parse a grid of characters. Every line must be the same width.
+    pub fn parse(input: &str) -> Result<Self> {
+        Self::parse_with(input, Some)
+    }
+
+    /// Parse a grid of characters, padding lines shorter than the longest
+    /// with `fill`.
+    pub fn padded(input: &str, fill: char) -> Self {
+        let width = input.lines().map(|l| l.chars().count()).max().unwrap_or(0);
+        let mut cells = Vec::new();
+        let mut height = 0;
+        for line in input.lines() {
+            let len = cells.len();
+            cells.extend(line.chars());
+            cells.resize(len + width, fill);
+            height += 1;
+        }
+
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::test_support::parse_position;
+
+    #[test]
+    fn test_groups() {
+        let input = "1\n2\n\n3\n\n\n4\n5\n";
+        assert_eq!(
+            split_groups(input),
+            vec![(0, "1\n2"), (3, "3"), (6, "4\n5")]
+        );
+
+        let sums = groups(input, |g| Ok(lines(g, value::<u32>)?.iter().sum::<u32>()));
+        assert_eq!(sums.unwrap(), vec![3, 3, 9]);
+        let error = groups("1\n\n2\nx\n", |g| lines(g, value::<u32>));
+        assert_eq!(parse_position(&error.unwrap_err()), (4, 1));
+    }
+
+    #[test]
+    fn test_ints() {
+        assert_eq!(ints("x=-2, y=15: 2-4").unwrap(), vec![-2, 15, 2, 4]);
+        assert_eq!(ints("-7 - 3").unwrap(), vec![-7, 3]);
+        assert!(ints("none").unwrap().is_empty());
+        assert_eq!(
+            parse_position(&ints("a 99999999999999999999").unwrap_err()),
+            (1, 3)
+        );
+    }
+
+    #[test]
+    fn test_template() {
+        let parsed: (usize, String, char) =
+            template("move {} from {} to {}", "move 12 from ab to c").unwrap();
+        assert_eq!(parsed, (12, "ab".to_string(), 'c'));
+
+        let error = template::<(u32, u32)>("{}-{}", "2-x");
+        assert_eq!(parse_position(&error.unwrap_err()), (1, 3));
+        let error = template::<(u32, u32)>("{} to {}", "2 from 3");
+        assert_eq!(parse_position(&error.unwrap_err()), (1, 9));
+        let error = template::<(u32, u32)>("a{},{}", "a,2");
+        assert_eq!(parse_position(&error.unwrap_err()), (1, 2));
+    }
+
+    #[test]
+    fn test_grid() {
+        let grid = Grid::parse("ab\ncd\n").unwrap();
+        assert_eq!((grid.width(), grid.height()), (2, 2));
+        assert_eq!(grid.get(1, 0), Some(&'b'));
+        assert_eq!(grid.column(0).collect::<String>(), "ac");
+        assert_eq!(parse_position(&Grid::parse("ab\nc\n").unwrap_err()), (2, 2));
+
+        let digits = Grid::parse_with("12\n3x", |c| c.to_digit(10));
+        assert_eq!(parse_position(&digits.unwrap_err()), (2, 2));
+
+        let padded = Grid::padded(" [A]\n [B] [C]", ' ');
+        assert_eq!(padded.width(), 8);
+        assert_eq!(padded.column(6).collect::<String>(), " C");
+    }
+}
@@ -0,0 +1,87 @@
+//! Summary statistics for repeated timings.
+
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// Summarise a non-empty set of samples. Returns `None` if there are none.
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let n = sorted.len();
+        let median = match n {
+            0 => return None,
+            n if n % 2 == 1 => sorted[n / 2],
+            n => (sorted[n / 2 - 1] + sorted[n / 2]) / 2,
+        };
+
+        Some(Self {
+            min: sorted[0],
+            median,
+            mean: sorted.iter().sum::<Duration>() / n as u32,
+            max: sorted[n - 1],
+        })
+    }
+}
+
+/// Time `f`, returning its result and how long it took.
+pub fn time<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = f();
+    (result, start.elapsed())
+}
+
+/// Format a duration with three significant figures in the largest unit that
+/// keeps it at least 1, e.g. `12.3µs` or `1.46s`.
+pub fn format(d: Duration) -> String {
+    let nanos = d.as_nanos() as f64;
+    let (value, unit) = if nanos < 1e3 {
+        (nanos, "ns")
+    } else if nanos < 1e6 {
+        (nanos / 1e3, "µs")
+    } else if nanos < 1e9 {
+        (nanos / 1e6, "ms")
+    } else {
+        (nanos / 1e9, "s")
+    };
+
+    let precision = match value {
+        v if v >= 100.0 => 0,
+        v if v >= 10.0 => 1,
+        _ => 2,
+    };
+    format!("{:.*}{}", precision, value, unit)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let samples = [4, 1, 3, 2].map(Duration::from_millis);
+        let stats = Stats::from_samples(&samples).unwrap();
+
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_micros(2500));
+        assert_eq!(stats.mean, Duration::from_micros(2500));
+        assert_eq!(stats.max, Duration::from_millis(4));
+        assert_eq!(Stats::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn test_format() {
+        assert_eq!(format(Duration::from_nanos(512)), "512ns");
+        assert_eq!(format(Duration::from_nanos(12_345)), "12.3µs");
+        assert_eq!(format(Duration::from_micros(1_459)), "1.46ms");
+        assert_eq!(format(Duration::from_secs(2)), "2.00s");
+    }
+}
@@ -0,0 +1,44 @@
+//! Reading the toml files that hold the config, answers, guesses and
+//! examples.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use serde::de::DeserializeOwned;
+
+use crate::error::{AocError, Result};
+
+/// The file to use: `configured` if set, otherwise `name` in the working
+/// directory, or in this repository if the working directory has none.
+pub fn locate(configured: Option<&Path>, name: &str) -> PathBuf {
+    if let Some(path) = configured {
+        return path.to_path_buf();
+    }
+    let local = PathBuf::from(name);
+    if local.is_file() {
+        local
+    } else {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join(name)
+    }
+}
+
+/// Parse `text`, naming `what` the file holds in errors.
+pub fn parse<T: DeserializeOwned>(text: &str, what: &str) -> Result<T> {
+    toml::from_str(text).map_err(|e| {
+        let (line, column) = e.line_col().unwrap_or((0, 0));
+        AocError::parse(line + 1, column + 1, format!("Invalid {}: {}", what, e))
+    })
+}
+
+/// Read and parse the file at `path`. Parse errors show the file and line
+/// they occurred on.
+pub fn load<T: DeserializeOwned>(path: &Path, what: &str) -> Result<T> {
+    let text = fs::read_to_string(path).map_err(|source| AocError::Io {
+        path: path.to_path_buf(),
+        source,
+    })?;
+
+    parse(&text, what).map_err(|e| e.with_source(&text).in_file(path))
+}
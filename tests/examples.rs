@@ -0,0 +1,58 @@
+//! Runs every registered day against its examples in
+//! `src/inputs/examples/day{N}.toml`.
+
+use aoc22::{config::Config, solutions, utils::examples};
+
+#[test]
+fn test_examples() {
+    let config = Config::default();
+    let mut failures = Vec::new();
+
+    for day in solutions::all() {
+        let examples = match examples::load(day.day, &config) {
+            Ok(examples) => examples,
+            Err(e) => {
+                failures.push(format!("day {}: {}", day.day, e));
+                continue;
+            }
+        };
+
+        for (i, example) in examples.iter().enumerate() {
+            let input = match day.parse(&example.input) {
+                Ok(input) => input,
+                Err(e) => {
+                    failures.push(format!("day {} example {}: {}", day.day, i + 1, e));
+                    continue;
+                }
+            };
+
+            let parts = [
+                (1, &example.part1, day.part1(&input)),
+                (2, &example.part2, day.part2(&input)),
+            ];
+            for (part, expected, actual) in parts {
+                let Some(expected) = expected else { continue };
+                match actual {
+                    Ok(actual) if actual == *expected => {}
+                    Ok(actual) => failures.push(format!(
+                        "day {} example {} part {}: expected {}, got {}",
+                        day.day,
+                        i + 1,
+                        part,
+                        expected,
+                        actual
+                    )),
+                    Err(e) => failures.push(format!(
+                        "day {} example {} part {}: {}",
+                        day.day,
+                        i + 1,
+                        part,
+                        e
+                    )),
+                }
+            }
+        }
+    }
+
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}